@@ -6,6 +6,7 @@ extern crate alloc;
 use alloc::{
     string::{String, ToString},
     vec,
+    vec::Vec,
 };
 
 use casper_contract::{
@@ -25,10 +26,19 @@ const TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_as_stored_c
 const APPROVE_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "approve_as_stored_contract";
 const TRANSFER_FROM_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_from_as_stored_contract";
 const CHECK_ALLOWANCE_OF_ENTRY_POINT_NAME: &str = "check_allowance_of";
+const TRY_TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "try_transfer_as_stored_contract";
+const TRY_TRANSFER_FROM_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str =
+    "try_transfer_from_as_stored_contract";
+const CHECK_BALANCES_OF_ENTRY_POINT_NAME: &str = "check_balances_of";
+const CHECK_ALLOWANCES_OF_ENTRY_POINT_NAME: &str = "check_allowances_of";
+const CHECK_SUPPORTED_INTERFACES_ENTRY_POINT_NAME: &str = "check_supported_interfaces";
+const CHECK_CONTRACT_VERSION_ENTRY_POINT_NAME: &str = "check_contract_version";
 const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
 const ADDRESS_RUNTIME_ARG_NAME: &str = "address";
 const OWNER_RUNTIME_ARG_NAME: &str = "owner";
 const SPENDER_RUNTIME_ARG_NAME: &str = "spender";
+const ADDRESSES_RUNTIME_ARG_NAME: &str = "addresses";
+const OWNER_SPENDER_PAIRS_RUNTIME_ARG_NAME: &str = "owner_spender_pairs";
 const RESULT_KEY: &str = "result";
 const CEP18_TEST_CALL_KEY: &str = "cep18_test_contract";
 
@@ -40,6 +50,12 @@ const TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "total_supply";
 const BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
 const TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
 const TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
+const TRY_TRANSFER_ENTRY_POINT_NAME: &str = "try_transfer";
+const TRY_TRANSFER_FROM_ENTRY_POINT_NAME: &str = "try_transfer_from";
+const BALANCES_OF_ENTRY_POINT_NAME: &str = "balances_of";
+const ALLOWANCES_OF_ENTRY_POINT_NAME: &str = "allowances_of";
+const SUPPORTED_INTERFACES_ENTRY_POINT_NAME: &str = "supported_interfaces";
+const CONTRACT_VERSION_ENTRY_POINT_NAME: &str = "contract_version";
 
 fn store_result<T: CLTyped + ToBytes>(result: T) {
     match runtime::get_key(RESULT_KEY) {
@@ -105,6 +121,78 @@ extern "C" fn check_allowance_of() {
     store_result(result);
 }
 
+#[no_mangle]
+extern "C" fn check_balances_of() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let addresses: Vec<Key> = runtime::get_named_arg(ADDRESSES_RUNTIME_ARG_NAME);
+
+    let balances_args = runtime_args! {
+        ADDRESSES_RUNTIME_ARG_NAME => addresses,
+    };
+    let result: Vec<U256> =
+        runtime::call_contract(token_contract, BALANCES_OF_ENTRY_POINT_NAME, balances_args);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_allowances_of() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let owner_spender_pairs: Vec<(Key, Key)> =
+        runtime::get_named_arg(OWNER_SPENDER_PAIRS_RUNTIME_ARG_NAME);
+
+    let allowances_args = runtime_args! {
+        OWNER_SPENDER_PAIRS_RUNTIME_ARG_NAME => owner_spender_pairs,
+    };
+    let result: Vec<U256> = runtime::call_contract(
+        token_contract,
+        ALLOWANCES_OF_ENTRY_POINT_NAME,
+        allowances_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_supported_interfaces() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let result: Vec<String> = runtime::call_contract(
+        token_contract,
+        SUPPORTED_INTERFACES_ENTRY_POINT_NAME,
+        RuntimeArgs::default(),
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_contract_version() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let result: (String, u32) = runtime::call_contract(
+        token_contract,
+        CONTRACT_VERSION_ENTRY_POINT_NAME,
+        RuntimeArgs::default(),
+    );
+
+    store_result(result);
+}
+
 #[no_mangle]
 extern "C" fn transfer_as_stored_contract() {
     let token_contract: ContractHash = ContractHash::new(
@@ -123,6 +211,29 @@ extern "C" fn transfer_as_stored_contract() {
     runtime::call_contract::<()>(token_contract, TRANSFER_ENTRY_POINT_NAME, transfer_args);
 }
 
+#[no_mangle]
+extern "C" fn try_transfer_as_stored_contract() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let recipient: Key = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    let try_transfer_args = runtime_args! {
+        RECIPIENT_RUNTIME_ARG_NAME => recipient,
+        AMOUNT_RUNTIME_ARG_NAME => amount,
+    };
+    let result: Result<(), u32> = runtime::call_contract(
+        token_contract,
+        TRY_TRANSFER_ENTRY_POINT_NAME,
+        try_transfer_args,
+    );
+
+    store_result(result);
+}
+
 #[no_mangle]
 extern "C" fn transfer_from_as_stored_contract() {
     let token_contract: ContractHash = ContractHash::new(
@@ -147,6 +258,31 @@ extern "C" fn transfer_from_as_stored_contract() {
     );
 }
 
+#[no_mangle]
+extern "C" fn try_transfer_from_as_stored_contract() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let owner: Key = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let recipient: Key = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    let try_transfer_from_args = runtime_args! {
+        OWNER_RUNTIME_ARG_NAME => owner,
+        RECIPIENT_RUNTIME_ARG_NAME => recipient,
+        AMOUNT_RUNTIME_ARG_NAME => amount,
+    };
+    let result: Result<(), u32> = runtime::call_contract(
+        token_contract,
+        TRY_TRANSFER_FROM_ENTRY_POINT_NAME,
+        try_transfer_from_args,
+    );
+
+    store_result(result);
+}
+
 #[no_mangle]
 extern "C" fn approve_as_stored_contract() {
     let token_contract: ContractHash = ContractHash::new(
@@ -237,12 +373,88 @@ pub extern "C" fn call() {
         EntryPointType::Contract,
     );
 
+    let try_transfer_as_stored_contract_entrypoint = EntryPoint::new(
+        String::from(TRY_TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let try_transfer_from_as_stored_contract_entrypoint = EntryPoint::new(
+        String::from(TRY_TRANSFER_FROM_AS_STORED_CONTRACT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_balances_of_entrypoint = EntryPoint::new(
+        String::from(CHECK_BALANCES_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESSES_RUNTIME_ARG_NAME, Vec::<Key>::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_allowances_of_entrypoint = EntryPoint::new(
+        String::from(CHECK_ALLOWANCES_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(
+                OWNER_SPENDER_PAIRS_RUNTIME_ARG_NAME,
+                Vec::<(Key, Key)>::cl_type(),
+            ),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_supported_interfaces_entrypoint = EntryPoint::new(
+        String::from(CHECK_SUPPORTED_INTERFACES_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            TOKEN_CONTRACT_RUNTIME_ARG_NAME,
+            ContractHash::cl_type(),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_contract_version_entrypoint = EntryPoint::new(
+        String::from(CHECK_CONTRACT_VERSION_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            TOKEN_CONTRACT_RUNTIME_ARG_NAME,
+            ContractHash::cl_type(),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     entry_points.add_entry_point(check_total_supply_entrypoint);
     entry_points.add_entry_point(check_balance_of_entrypoint);
     entry_points.add_entry_point(check_allowance_of_entrypoint);
     entry_points.add_entry_point(transfer_as_stored_contract_entrypoint);
     entry_points.add_entry_point(approve_as_stored_contract_entrypoint);
     entry_points.add_entry_point(transfer_from_as_stored_contract_entrypoint);
+    entry_points.add_entry_point(try_transfer_as_stored_contract_entrypoint);
+    entry_points.add_entry_point(try_transfer_from_as_stored_contract_entrypoint);
+    entry_points.add_entry_point(check_balances_of_entrypoint);
+    entry_points.add_entry_point(check_allowances_of_entrypoint);
+    entry_points.add_entry_point(check_supported_interfaces_entrypoint);
+    entry_points.add_entry_point(check_contract_version_entrypoint);
 
     let (_contract_hash, _version) = storage::new_contract(
         entry_points,
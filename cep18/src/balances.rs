@@ -4,7 +4,11 @@ use alloc::string::String;
 use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
 use casper_types::{bytesrepr::ToBytes, Key, URef, U256};
 
-use crate::{constants::BALANCES, error::Cep18Error, utils};
+use crate::{
+    constants::{BALANCES, UNDISTRIBUTED_SUPPLY},
+    error::Cep18Error,
+    holds, interest, utils, vesting,
+};
 
 /// Creates a dictionary item key for a dictionary item, by base64 encoding the Key argument
 /// since stringified Keys are too long to be used as dictionary keys.
@@ -25,25 +29,84 @@ pub(crate) fn get_balances_uref() -> URef {
     utils::get_uref(BALANCES)
 }
 
-/// Writes token balance of a specified account into a dictionary.
-pub(crate) fn write_balance_to(balances_uref: URef, address: Key, amount: U256) {
+/// Writes the stored token balance of a specified account into a dictionary.
+///
+/// Interest-bearing balances are stored as amounts scaled by the accrual index.
+fn write_scaled_balance_to(balances_uref: URef, address: Key, scaled: U256) {
+    interest::accrue();
     let dictionary_item_key = make_dictionary_item_key(address);
-    storage::dictionary_put(balances_uref, &dictionary_item_key, amount);
+    storage::dictionary_put(balances_uref, &dictionary_item_key, scaled);
+}
+
+/// Reads the stored token balance of a specified account.
+fn read_scaled_balance_from(balances_uref: URef, address: Key) -> U256 {
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_get(balances_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
 }
 
 /// Reads token balance of a specified account.
 ///
 /// If a given account does not have balances in the system, then a 0 is returned.
 pub(crate) fn read_balance_from(balances_uref: URef, address: Key) -> U256 {
-    let dictionary_item_key = make_dictionary_item_key(address);
+    interest::to_nominal(read_scaled_balance_from(balances_uref, address))
+}
 
-    storage::dictionary_get(balances_uref, &dictionary_item_key)
-        .unwrap_or_revert()
-        .unwrap_or_default()
+/// Adds the stored amount `scaled` to the balance of `address`.
+fn credit_scaled_balance(
+    balances_uref: URef,
+    address: Key,
+    scaled: U256,
+) -> Result<(), Cep18Error> {
+    let new_balance = read_scaled_balance_from(balances_uref, address)
+        .checked_add(scaled)
+        .ok_or(Cep18Error::Overflow)?;
+    write_scaled_balance_to(balances_uref, address, new_balance);
+    Ok(())
+}
+
+/// Subtracts the stored amount `scaled` from the balance of `address`, which has to keep covering
+/// its locked and held amounts.
+fn debit_scaled_balance(balances_uref: URef, address: Key, scaled: U256) -> Result<(), Cep18Error> {
+    let new_balance = read_scaled_balance_from(balances_uref, address)
+        .checked_sub(scaled)
+        .ok_or(Cep18Error::InsufficientBalance)?;
+    check_spendable(address, interest::to_nominal(new_balance))?;
+    write_scaled_balance_to(balances_uref, address, new_balance);
+    Ok(())
+}
+
+/// Credits `amount` tokens to `address`, returning the stored amount credited.
+///
+/// Credits round the stored amount down, so that they never create more than `amount` tokens.
+pub(crate) fn credit_balance(
+    balances_uref: URef,
+    address: Key,
+    amount: U256,
+) -> Result<U256, Cep18Error> {
+    let scaled = interest::to_scaled(amount);
+    credit_scaled_balance(balances_uref, address, scaled)?;
+    Ok(scaled)
+}
+
+/// Debits `amount` spendable tokens from `address`, returning the stored amount debited.
+///
+/// Debits round the stored amount up, so that they never take less than `amount` tokens.
+pub(crate) fn debit_balance(
+    balances_uref: URef,
+    address: Key,
+    amount: U256,
+) -> Result<U256, Cep18Error> {
+    let scaled = interest::to_scaled_ceil(amount);
+    debit_scaled_balance(balances_uref, address, scaled)?;
+    Ok(scaled)
 }
 
 /// Transfer tokens from the `sender` to the `recipient`.
 ///
+/// Only the unlocked part of the sender balance which is not on hold can be transferred.
+///
 /// This function should not be used directly by contract's entrypoint as it does not validate the
 /// sender.
 pub(crate) fn transfer_balance(
@@ -54,24 +117,52 @@ pub(crate) fn transfer_balance(
     if sender == recipient || amount.is_zero() {
         return Ok(());
     }
+    check_distributed()?;
 
+    // The recipient is credited the stored amount debited from the sender, so that transfers
+    // neither create nor destroy tokens when rounding.
     let balances_uref = get_balances_uref();
-    let new_sender_balance = {
-        let sender_balance = read_balance_from(balances_uref, sender);
-        sender_balance
-            .checked_sub(amount)
-            .ok_or(Cep18Error::InsufficientBalance)?
-    };
-
-    let new_recipient_balance = {
-        let recipient_balance = read_balance_from(balances_uref, recipient);
-        recipient_balance
-            .checked_add(amount)
-            .ok_or(Cep18Error::Overflow)?
-    };
-
-    write_balance_to(balances_uref, sender, new_sender_balance);
-    write_balance_to(balances_uref, recipient, new_recipient_balance);
+    let scaled = debit_balance(balances_uref, sender, amount)?;
+    credit_scaled_balance(balances_uref, recipient, scaled)
+}
 
+/// Moves `amount` tokens of `sender` into the custody of the contract, returning the stored amount
+/// held.
+///
+/// Escrows keep track of the stored amount rather than the nominal one, so that their release
+/// moves exactly what was escrowed along with the interest it accrued meanwhile.
+pub(crate) fn escrow_balance(sender: Key, amount: U256) -> Result<U256, Cep18Error> {
+    check_distributed()?;
+    let balances_uref = get_balances_uref();
+    let scaled = debit_balance(balances_uref, sender, amount)?;
+    credit_scaled_balance(balances_uref, utils::get_package_key(), scaled)?;
+    Ok(scaled)
+}
+
+/// Releases the stored amount `scaled` held in the custody of the contract to `recipient`,
+/// returning the amount of tokens released.
+pub(crate) fn release_balance(recipient: Key, scaled: U256) -> Result<U256, Cep18Error> {
+    let balances_uref = get_balances_uref();
+    debit_scaled_balance(balances_uref, utils::get_package_key(), scaled)?;
+    credit_scaled_balance(balances_uref, recipient, scaled)?;
+    Ok(interest::to_nominal(scaled))
+}
+
+/// Checks that the initial distribution of the total supply is complete, the token being live.
+pub(crate) fn check_distributed() -> Result<(), Cep18Error> {
+    if !utils::read_from::<U256>(UNDISTRIBUTED_SUPPLY).is_zero() {
+        return Err(Cep18Error::InitialDistributionPending);
+    }
+    Ok(())
+}
+
+/// Checks that the `new_balance` of `owner` still covers its locked and held amounts.
+pub(crate) fn check_spendable(owner: Key, new_balance: U256) -> Result<(), Cep18Error> {
+    let unlocked_balance = new_balance
+        .checked_sub(vesting::locked_balance_of(owner))
+        .ok_or(Cep18Error::AmountLocked)?;
+    if unlocked_balance < holds::balance_on_hold(owner) {
+        return Err(Cep18Error::AmountOnHold);
+    }
     Ok(())
 }
@@ -0,0 +1,164 @@
+//! Implementation of claimable transfers, escrowed by the contract until the recipient claims
+//! them or the sender reclaims them after their expiry.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key, URef, U256,
+};
+
+use crate::{
+    constants::{CLAIMS, CLAIMS_COUNT, MAX_PENDING_CLAIMS, RECIPIENT_CLAIMS},
+    error::Cep18Error,
+    utils,
+};
+
+/// An amount escrowed by `sender` for `recipient`, which `sender` can reclaim from block time
+/// `expiry`. The contract holds `scaled_amount`, the amount stored for it in its balance.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Claim {
+    pub sender: Key,
+    pub recipient: Key,
+    pub amount: U256,
+    pub scaled_amount: U256,
+    pub expiry: u64,
+}
+
+impl CLTyped for Claim {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Claim {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.sender.to_bytes()?);
+        result.append(&mut self.recipient.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.scaled_amount.to_bytes()?);
+        result.append(&mut self.expiry.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.sender.serialized_length()
+            + self.recipient.serialized_length()
+            + self.amount.serialized_length()
+            + self.scaled_amount.serialized_length()
+            + self.expiry.serialized_length()
+    }
+}
+
+impl FromBytes for Claim {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (sender, remainder) = Key::from_bytes(bytes)?;
+        let (recipient, remainder) = Key::from_bytes(remainder)?;
+        let (amount, remainder) = U256::from_bytes(remainder)?;
+        let (scaled_amount, remainder) = U256::from_bytes(remainder)?;
+        let (expiry, remainder) = u64::from_bytes(remainder)?;
+        Ok((
+            Claim {
+                sender,
+                recipient,
+                amount,
+                scaled_amount,
+                expiry,
+            },
+            remainder,
+        ))
+    }
+}
+
+/// Creates a dictionary item key for a transfer id.
+#[inline]
+fn make_transfer_item_key(transfer_id: u64) -> String {
+    hex::encode(transfer_id.to_bytes().unwrap_or_revert())
+}
+
+/// Creates a dictionary item key for a recipient.
+#[inline]
+fn make_recipient_item_key(recipient: Key) -> String {
+    base64::encode(recipient.to_bytes().unwrap_or_revert())
+}
+
+/// Getter for the "claims" dictionary URef.
+#[inline]
+pub(crate) fn get_claims_uref() -> URef {
+    utils::get_uref(CLAIMS)
+}
+
+/// Reads the pending claim of a transfer id.
+pub(crate) fn read_claim_from(claims_uref: URef, transfer_id: u64) -> Option<Claim> {
+    storage::dictionary_get(claims_uref, &make_transfer_item_key(transfer_id))
+        .unwrap_or_revert()
+        .flatten()
+}
+
+/// Writes the pending claim of a transfer id, `None` closing it.
+fn write_claim_to(claims_uref: URef, transfer_id: u64, claim: Option<Claim>) {
+    storage::dictionary_put(claims_uref, &make_transfer_item_key(transfer_id), claim)
+}
+
+/// Reads the transfer ids of the pending claims of a recipient, oldest first.
+pub(crate) fn read_pending_claims(recipient: Key) -> Vec<u64> {
+    storage::dictionary_get(
+        utils::get_uref(RECIPIENT_CLAIMS),
+        &make_recipient_item_key(recipient),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_default()
+}
+
+/// Writes the transfer ids of the pending claims of a recipient.
+fn write_pending_claims(recipient: Key, pending_claims: Vec<u64>) {
+    storage::dictionary_put(
+        utils::get_uref(RECIPIENT_CLAIMS),
+        &make_recipient_item_key(recipient),
+        pending_claims,
+    )
+}
+
+/// Records a claim under a new transfer id, returning it. The escrowed amount has to be moved to
+/// the contract by the caller.
+pub(crate) fn create_claim(claim: Claim) -> Result<u64, Cep18Error> {
+    if claim.amount.is_zero() {
+        return Err(Cep18Error::InvalidClaimAmount);
+    }
+    if claim.expiry <= u64::from(runtime::get_blocktime()) {
+        return Err(Cep18Error::InvalidClaimExpiry);
+    }
+    let mut pending_claims = read_pending_claims(claim.recipient);
+    if pending_claims.len() >= MAX_PENDING_CLAIMS {
+        return Err(Cep18Error::TooManyPendingClaims);
+    }
+    let claims_count_uref = utils::get_uref(CLAIMS_COUNT);
+    let transfer_id: u64 = storage::read(claims_count_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    storage::write(
+        claims_count_uref,
+        transfer_id.checked_add(1).ok_or(Cep18Error::Overflow)?,
+    );
+
+    pending_claims.push(transfer_id);
+    write_pending_claims(claim.recipient, pending_claims);
+    write_claim_to(get_claims_uref(), transfer_id, Some(claim));
+    Ok(transfer_id)
+}
+
+/// Closes the pending claim of a transfer id, returning it so the caller can move the escrowed
+/// amount.
+pub(crate) fn close_claim(transfer_id: u64) -> Result<Claim, Cep18Error> {
+    let claims_uref = get_claims_uref();
+    let claim = read_claim_from(claims_uref, transfer_id).ok_or(Cep18Error::ClaimNotFound)?;
+    let mut pending_claims = read_pending_claims(claim.recipient);
+    pending_claims.retain(|id| *id != transfer_id);
+    write_pending_claims(claim.recipient, pending_claims);
+    write_claim_to(claims_uref, transfer_id, None);
+    Ok(claim)
+}
@@ -18,6 +18,18 @@ pub const ACCESS_KEY_NAME_PREFIX: &str = "cep18_contract_package_access_";
 pub const CONTRACT_NAME_PREFIX: &str = "cep18_contract_hash_";
 pub const CONTRACT_VERSION_PREFIX: &str = "cep18_contract_version_";
 
+/// Maximum length of the token `name`.
+pub const MAX_NAME_LENGTH: usize = 64;
+/// Maximum length of the token `symbol`.
+pub const MAX_SYMBOL_LENGTH: usize = 11;
+/// Maximum number of token `decimals`.
+pub const MAX_DECIMALS: u8 = 18;
+/// Maximum number of pending claims of a recipient.
+pub const MAX_PENDING_CLAIMS: usize = 100;
+/// Version of the layout of the contract storage, to be bumped whenever an upgrade has to migrate
+/// the named keys or dictionaries.
+pub const STORAGE_SCHEMA_VERSION: u32 = 1;
+
 /// Name of `name` entry point.
 pub const NAME_ENTRY_POINT_NAME: &str = "name";
 /// Name of `symbol` entry point.
@@ -47,6 +59,127 @@ pub const CHANGE_SECURITY_ENTRY_POINT_NAME: &str = "change_security";
 
 pub const INCREASE_ALLOWANCE_ENTRY_POINT_NAME: &str = "increase_allowance";
 pub const DECREASE_ALLOWANCE_ENTRY_POINT_NAME: &str = "decrease_allowance";
+/// Name of `approve_action` entry point.
+pub const APPROVE_ACTION_ENTRY_POINT_NAME: &str = "approve_action";
+/// Name of `set_quorum` entry point.
+pub const SET_QUORUM_ENTRY_POINT_NAME: &str = "set_quorum";
+/// Name of `configure_minter` entry point.
+pub const CONFIGURE_MINTER_ENTRY_POINT_NAME: &str = "configure_minter";
+/// Name of `remove_minter` entry point.
+pub const REMOVE_MINTER_ENTRY_POINT_NAME: &str = "remove_minter";
+/// Name of `minter_allowance` entry point.
+pub const MINTER_ALLOWANCE_ENTRY_POINT_NAME: &str = "minter_allowance";
+/// Name of `set_mint_rate_limit` entry point.
+pub const SET_MINT_RATE_LIMIT_ENTRY_POINT_NAME: &str = "set_mint_rate_limit";
+/// Name of `remaining_mint_capacity` entry point.
+pub const REMAINING_MINT_CAPACITY_ENTRY_POINT_NAME: &str = "remaining_mint_capacity";
+/// Name of `freeze` entry point.
+pub const FREEZE_ENTRY_POINT_NAME: &str = "freeze";
+/// Name of `unfreeze` entry point.
+pub const UNFREEZE_ENTRY_POINT_NAME: &str = "unfreeze";
+/// Name of `detect_transfer_restriction` entry point.
+pub const DETECT_TRANSFER_RESTRICTION_ENTRY_POINT_NAME: &str = "detect_transfer_restriction";
+/// Name of `message_for_transfer_restriction` entry point.
+pub const MESSAGE_FOR_TRANSFER_RESTRICTION_ENTRY_POINT_NAME: &str =
+    "message_for_transfer_restriction";
+/// Name of `add_to_whitelist` entry point.
+pub const ADD_TO_WHITELIST_ENTRY_POINT_NAME: &str = "add_to_whitelist";
+/// Name of `remove_from_whitelist` entry point.
+pub const REMOVE_FROM_WHITELIST_ENTRY_POINT_NAME: &str = "remove_from_whitelist";
+/// Name of `is_whitelisted` entry point.
+pub const IS_WHITELISTED_ENTRY_POINT_NAME: &str = "is_whitelisted";
+/// Name of `mint_locked` entry point.
+pub const MINT_LOCKED_ENTRY_POINT_NAME: &str = "mint_locked";
+/// Name of `locked_balance_of` entry point.
+pub const LOCKED_BALANCE_OF_ENTRY_POINT_NAME: &str = "locked_balance_of";
+/// Name of `releasable_of` entry point.
+pub const RELEASABLE_OF_ENTRY_POINT_NAME: &str = "releasable_of";
+/// Name of `force_transfer` entry point.
+pub const FORCE_TRANSFER_ENTRY_POINT_NAME: &str = "force_transfer";
+/// Name of `clawback` entry point.
+pub const CLAWBACK_ENTRY_POINT_NAME: &str = "clawback";
+/// Name of `initiate_recovery` entry point.
+pub const INITIATE_RECOVERY_ENTRY_POINT_NAME: &str = "initiate_recovery";
+/// Name of `cancel_recovery` entry point.
+pub const CANCEL_RECOVERY_ENTRY_POINT_NAME: &str = "cancel_recovery";
+/// Name of `finalize_recovery` entry point.
+pub const FINALIZE_RECOVERY_ENTRY_POINT_NAME: &str = "finalize_recovery";
+/// Name of `pending_recovery` entry point.
+pub const PENDING_RECOVERY_ENTRY_POINT_NAME: &str = "pending_recovery";
+/// Name of `hold` entry point.
+pub const HOLD_ENTRY_POINT_NAME: &str = "hold";
+/// Name of `execute_hold` entry point.
+pub const EXECUTE_HOLD_ENTRY_POINT_NAME: &str = "execute_hold";
+/// Name of `release_hold` entry point.
+pub const RELEASE_HOLD_ENTRY_POINT_NAME: &str = "release_hold";
+/// Name of `balance_on_hold` entry point.
+pub const BALANCE_ON_HOLD_ENTRY_POINT_NAME: &str = "balance_on_hold";
+/// Name of `hold_status` entry point.
+pub const HOLD_STATUS_ENTRY_POINT_NAME: &str = "hold_status";
+/// Name of `authorize_operator` entry point.
+pub const AUTHORIZE_OPERATOR_ENTRY_POINT_NAME: &str = "authorize_operator";
+/// Name of `revoke_operator` entry point.
+pub const REVOKE_OPERATOR_ENTRY_POINT_NAME: &str = "revoke_operator";
+/// Name of `is_operator_for` entry point.
+pub const IS_OPERATOR_FOR_ENTRY_POINT_NAME: &str = "is_operator_for";
+/// Name of `operator_send` entry point.
+pub const OPERATOR_SEND_ENTRY_POINT_NAME: &str = "operator_send";
+/// Name of `set_transfer_allowed` entry point.
+pub const SET_TRANSFER_ALLOWED_ENTRY_POINT_NAME: &str = "set_transfer_allowed";
+/// Name of `set_rate` entry point.
+pub const SET_RATE_ENTRY_POINT_NAME: &str = "set_rate";
+/// Name of `send_claimable` entry point.
+pub const SEND_CLAIMABLE_ENTRY_POINT_NAME: &str = "send_claimable";
+/// Name of `claim` entry point.
+pub const CLAIM_ENTRY_POINT_NAME: &str = "claim";
+/// Name of `reclaim` entry point.
+pub const RECLAIM_ENTRY_POINT_NAME: &str = "reclaim";
+/// Name of `pending_claims` entry point.
+pub const PENDING_CLAIMS_ENTRY_POINT_NAME: &str = "pending_claims";
+/// Name of `pending_claim` entry point.
+pub const PENDING_CLAIM_ENTRY_POINT_NAME: &str = "pending_claim";
+/// Name of `create_stream` entry point.
+pub const CREATE_STREAM_ENTRY_POINT_NAME: &str = "create_stream";
+/// Name of `withdraw_from_stream` entry point.
+pub const WITHDRAW_FROM_STREAM_ENTRY_POINT_NAME: &str = "withdraw_from_stream";
+/// Name of `cancel_stream` entry point.
+pub const CANCEL_STREAM_ENTRY_POINT_NAME: &str = "cancel_stream";
+/// Name of `withdrawable_from_stream` entry point.
+pub const WITHDRAWABLE_FROM_STREAM_ENTRY_POINT_NAME: &str = "withdrawable_from_stream";
+/// Name of `htlc_lock` entry point.
+pub const HTLC_LOCK_ENTRY_POINT_NAME: &str = "htlc_lock";
+/// Name of `htlc_redeem` entry point.
+pub const HTLC_REDEEM_ENTRY_POINT_NAME: &str = "htlc_redeem";
+/// Name of `htlc_refund` entry point.
+pub const HTLC_REFUND_ENTRY_POINT_NAME: &str = "htlc_refund";
+/// Name of `set_modalities` entry point.
+pub const SET_MODALITIES_ENTRY_POINT_NAME: &str = "set_modalities";
+/// Name of `get_events` entry point.
+pub const GET_EVENTS_ENTRY_POINT_NAME: &str = "get_events";
+/// Name of `init_distribute` entry point.
+pub const INIT_DISTRIBUTE_ENTRY_POINT_NAME: &str = "init_distribute";
+/// Name of `set_metadata` entry point.
+pub const SET_METADATA_ENTRY_POINT_NAME: &str = "set_metadata";
+/// Name of `token_info` entry point.
+pub const TOKEN_INFO_ENTRY_POINT_NAME: &str = "token_info";
+/// Name of `supported_interfaces` entry point.
+pub const SUPPORTED_INTERFACES_ENTRY_POINT_NAME: &str = "supported_interfaces";
+/// Name of `contract_version` entry point.
+pub const CONTRACT_VERSION_ENTRY_POINT_NAME: &str = "contract_version";
+/// Name of `security_badge_of` entry point.
+pub const SECURITY_BADGE_OF_ENTRY_POINT_NAME: &str = "security_badge_of";
+/// Name of `admins` entry point.
+pub const ADMINS_ENTRY_POINT_NAME: &str = "admins";
+/// Name of `minters` entry point.
+pub const MINTERS_ENTRY_POINT_NAME: &str = "minters";
+/// Name of `try_transfer` entry point.
+pub const TRY_TRANSFER_ENTRY_POINT_NAME: &str = "try_transfer";
+/// Name of `try_transfer_from` entry point.
+pub const TRY_TRANSFER_FROM_ENTRY_POINT_NAME: &str = "try_transfer_from";
+/// Name of `balances_of` entry point.
+pub const BALANCES_OF_ENTRY_POINT_NAME: &str = "balances_of";
+/// Name of `allowances_of` entry point.
+pub const ALLOWANCES_OF_ENTRY_POINT_NAME: &str = "allowances_of";
 
 /// Name of `address` runtime argument.
 pub const ADDRESS: &str = "address";
@@ -61,9 +194,175 @@ pub const RECIPIENT: &str = "recipient";
 pub const PACKAGE_HASH: &str = "package_hash";
 pub const EVENTS_MODE: &str = "events_mode";
 pub const SECURITY_BADGES: &str = "security_badges";
+/// Name of dictionary-key for the keys holding each security badge.
+pub const ROLE_MEMBERS: &str = "role_members";
+/// Name of dictionary-key for the index of each key among the holders of its security badge.
+pub const ROLE_MEMBER_INDICES: &str = "role_member_indices";
 pub const ADMIN_LIST: &str = "admin_list";
 pub const MINTER_LIST: &str = "minter_list";
+pub const MASTER_MINTER_LIST: &str = "master_minter_list";
+pub const KYC_OPERATOR_LIST: &str = "kyc_operator_list";
+pub const ENFORCER_LIST: &str = "enforcer_list";
+pub const NOTARY_LIST: &str = "notary_list";
 pub const BURNER_LIST: &str = "burner_list";
 pub const NONE_LIST: &str = "none_list";
 pub const MINT_AND_BURN_LIST: &str = "mint_and_burn_list";
 pub const ENABLE_MINT_BURN: &str = "enable_mint_burn";
+/// Name of named-key and runtime argument for the number of admin approvals required.
+pub const ADMIN_QUORUM: &str = "admin_quorum";
+/// Name of named-key and runtime argument for the mint amount above which a quorum is required.
+pub const MINT_QUORUM_THRESHOLD: &str = "mint_quorum_threshold";
+/// Name of dictionary-key for `action_approvals`
+pub const ACTION_APPROVALS: &str = "action_approvals";
+/// Name of `action_hash` runtime argument.
+pub const ACTION_HASH: &str = "action_hash";
+/// Name of dictionary-key for `minter_allowance`
+pub const MINTER_ALLOWANCE: &str = "minter_allowance";
+/// Name of `minter` runtime argument.
+pub const MINTER: &str = "minter";
+/// Name of `allowance` runtime argument.
+pub const ALLOWANCE: &str = "allowance";
+/// Name of named-key for the global `mint_rate_limit`
+pub const MINT_RATE_LIMIT: &str = "mint_rate_limit";
+/// Name of named-key for the amount minted during the current global epoch.
+pub const MINT_EPOCH_USAGE: &str = "mint_epoch_usage";
+/// Name of dictionary-key for `minter_rate_limits`
+pub const MINTER_RATE_LIMITS: &str = "minter_rate_limits";
+/// Name of dictionary-key for `minter_epoch_usage`
+pub const MINTER_EPOCH_USAGE: &str = "minter_epoch_usage";
+/// Name of `epoch_duration` runtime argument.
+pub const EPOCH_DURATION: &str = "epoch_duration";
+/// Name of `epoch_limit` runtime argument.
+pub const EPOCH_LIMIT: &str = "epoch_limit";
+/// Name of dictionary-key for `frozen`
+pub const FROZEN: &str = "frozen";
+/// Name of `account` runtime argument.
+pub const ACCOUNT: &str = "account";
+/// Name of `from` runtime argument.
+pub const FROM: &str = "from";
+/// Name of `to` runtime argument.
+pub const TO: &str = "to";
+/// Name of `restriction_code` runtime argument.
+pub const RESTRICTION_CODE: &str = "restriction_code";
+/// Name of named-key and runtime argument for the `transfer_mode` modality.
+pub const TRANSFER_MODE: &str = "transfer_mode";
+/// Name of dictionary-key for `whitelist`
+pub const WHITELIST: &str = "whitelist";
+/// Name of `accounts` runtime argument.
+pub const ACCOUNTS: &str = "accounts";
+/// Name of dictionary-key for `locks`
+pub const LOCKS: &str = "locks";
+/// Name of `start` runtime argument.
+pub const START: &str = "start";
+/// Name of `cliff` runtime argument.
+pub const CLIFF: &str = "cliff";
+/// Name of `duration` runtime argument.
+pub const DURATION: &str = "duration";
+/// Name of named-key and runtime argument for the `enforcement_mode` modality.
+pub const ENFORCEMENT_MODE: &str = "enforcement_mode";
+/// Name of `reason` runtime argument.
+pub const REASON: &str = "reason";
+/// Name of dictionary-key for `recoveries`
+pub const RECOVERIES: &str = "recoveries";
+/// Name of named-key and runtime argument for the `recovery_delay` in milliseconds.
+pub const RECOVERY_DELAY: &str = "recovery_delay";
+/// Default challenge window of recoveries, one week in milliseconds.
+pub const DEFAULT_RECOVERY_DELAY: u64 = 7 * 24 * 60 * 60 * 1000;
+/// Name of `lost` runtime argument.
+pub const LOST: &str = "lost";
+/// Name of `new_owner` runtime argument.
+pub const NEW_OWNER: &str = "new_owner";
+/// Name of dictionary-key for `holds`
+pub const HOLDS: &str = "holds";
+/// Name of dictionary-key for `account_holds`
+pub const ACCOUNT_HOLDS: &str = "account_holds";
+/// Name of `operation_id` runtime argument.
+pub const OPERATION_ID: &str = "operation_id";
+/// Name of `expiration` runtime argument.
+pub const EXPIRATION: &str = "expiration";
+/// Name of dictionary-key for `operators`
+pub const OPERATORS: &str = "operators";
+/// Name of named-key and runtime argument for the `default_operators`.
+pub const DEFAULT_OPERATORS: &str = "default_operators";
+/// Name of `operator` runtime argument.
+pub const OPERATOR: &str = "operator";
+/// Name of `holder` runtime argument.
+pub const HOLDER: &str = "holder";
+/// Name of `data` runtime argument.
+pub const DATA: &str = "data";
+/// Name of named-key and runtime argument for the `transferability` modality.
+pub const TRANSFERABILITY: &str = "transferability";
+/// Name of dictionary-key and runtime argument for the `transfer_allow_list`.
+pub const TRANSFER_ALLOW_LIST: &str = "transfer_allow_list";
+/// Name of `allowed` runtime argument.
+pub const ALLOWED: &str = "allowed";
+/// Name of named-key and runtime argument for the `interest_mode` modality.
+pub const INTEREST_MODE: &str = "interest_mode";
+/// Name of named-key and runtime argument for the `interest_rate`.
+pub const INTEREST_RATE: &str = "interest_rate";
+/// Name of named-key for the `accrual_index`.
+pub const ACCRUAL_INDEX: &str = "accrual_index";
+/// Name of named-key for the `last_accrual` block time.
+pub const LAST_ACCRUAL: &str = "last_accrual";
+/// Name of `rate` runtime argument.
+pub const RATE: &str = "rate";
+/// Name of dictionary-key for `claims`
+pub const CLAIMS: &str = "claims";
+/// Name of dictionary-key for `recipient_claims`
+pub const RECIPIENT_CLAIMS: &str = "recipient_claims";
+/// Name of named-key for the `claims_count`.
+pub const CLAIMS_COUNT: &str = "claims_count";
+/// Name of `transfer_id` runtime argument.
+pub const TRANSFER_ID: &str = "transfer_id";
+/// Name of `expiry` runtime argument.
+pub const EXPIRY: &str = "expiry";
+/// Name of `count` runtime argument.
+pub const COUNT: &str = "count";
+/// Name of dictionary-key for `streams`
+pub const STREAMS: &str = "streams";
+/// Name of named-key for the `streams_count`.
+pub const STREAMS_COUNT: &str = "streams_count";
+/// Name of `stream_id` runtime argument.
+pub const STREAM_ID: &str = "stream_id";
+/// Name of `deposit` runtime argument.
+pub const DEPOSIT: &str = "deposit";
+/// Name of `stop` runtime argument.
+pub const STOP: &str = "stop";
+/// Name of dictionary-key for `htlcs`
+pub const HTLCS: &str = "htlcs";
+/// Name of named-key for the `htlcs_count`.
+pub const HTLCS_COUNT: &str = "htlcs_count";
+/// Name of `lock_id` runtime argument.
+pub const LOCK_ID: &str = "lock_id";
+/// Name of `hashlock` runtime argument.
+pub const HASHLOCK: &str = "hashlock";
+/// Name of `timelock` runtime argument.
+pub const TIMELOCK: &str = "timelock";
+/// Name of `preimage` runtime argument.
+pub const PREIMAGE: &str = "preimage";
+/// Name of named-key holding the schemas of the Casper Event Standard.
+pub const EVENTS_SCHEMA: &str = "__events_schema";
+/// Name of dictionary-key for the native `events` log.
+pub const EVENTS: &str = "events";
+/// Name of named-key for the `events_length` of the native event log.
+pub const EVENTS_LENGTH: &str = "events_length";
+/// Name of runtime argument for the `initial_balances`.
+pub const INITIAL_BALANCES: &str = "initial_balances";
+/// Name of runtime argument for the `chunked_distribution`.
+pub const CHUNKED_DISTRIBUTION: &str = "chunked_distribution";
+/// Name of named-key for the part of the total supply not distributed yet.
+pub const UNDISTRIBUTED_SUPPLY: &str = "undistributed_supply";
+/// Name of named-key and runtime argument for the `description`.
+pub const DESCRIPTION: &str = "description";
+/// Name of named-key and runtime argument for the `icon_url`.
+pub const ICON_URL: &str = "icon_url";
+/// Name of named-key and runtime argument for the `website`.
+pub const WEBSITE: &str = "website";
+/// Name of named-key and runtime argument for the free-form `metadata`.
+pub const METADATA: &str = "metadata";
+/// Name of named-key for the version of the layout of the contract storage.
+pub const SCHEMA_VERSION: &str = "schema_version";
+/// Name of runtime argument for the `addresses` queried by `balances_of`.
+pub const ADDRESSES: &str = "addresses";
+/// Name of runtime argument for the `(owner, spender)` pairs queried by `allowances_of`.
+pub const OWNER_SPENDER_PAIRS: &str = "owner_spender_pairs";
@@ -1,18 +1,52 @@
 //! Contains definition of the entry points.
-use alloc::{string::String, vec, vec::Vec};
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
 
 use casper_types::{
-    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
-    U256,
+    bytesrepr::Bytes, CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
+    Key, Parameter, U256,
 };
 
-use crate::constants::{
-    ADDRESS, ALLOWANCE_ENTRY_POINT_NAME, AMOUNT, APPROVE_ENTRY_POINT_NAME,
-    BALANCE_OF_ENTRY_POINT_NAME, BURN_ENTRY_POINT_NAME, CHANGE_SECURITY_ENTRY_POINT_NAME,
-    DECIMALS_ENTRY_POINT_NAME, DECREASE_ALLOWANCE_ENTRY_POINT_NAME,
-    INCREASE_ALLOWANCE_ENTRY_POINT_NAME, INIT_ENTRY_POINT_NAME, MINT_ENTRY_POINT_NAME,
-    NAME_ENTRY_POINT_NAME, OWNER, RECIPIENT, SPENDER, SYMBOL_ENTRY_POINT_NAME,
-    TOTAL_SUPPLY_ENTRY_POINT_NAME, TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
+use crate::{
+    constants::{
+        ACCOUNT, ACCOUNTS, ACTION_HASH, ADDRESS, ADDRESSES, ADD_TO_WHITELIST_ENTRY_POINT_NAME,
+        ADMINS_ENTRY_POINT_NAME, ADMIN_QUORUM, ALLOWANCE, ALLOWANCES_OF_ENTRY_POINT_NAME,
+        ALLOWANCE_ENTRY_POINT_NAME, ALLOWED, AMOUNT, APPROVE_ACTION_ENTRY_POINT_NAME,
+        APPROVE_ENTRY_POINT_NAME, AUTHORIZE_OPERATOR_ENTRY_POINT_NAME,
+        BALANCES_OF_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME,
+        BALANCE_ON_HOLD_ENTRY_POINT_NAME, BURN_ENTRY_POINT_NAME, CANCEL_RECOVERY_ENTRY_POINT_NAME,
+        CANCEL_STREAM_ENTRY_POINT_NAME, CHANGE_SECURITY_ENTRY_POINT_NAME, CLAIM_ENTRY_POINT_NAME,
+        CLAWBACK_ENTRY_POINT_NAME, CLIFF, CONFIGURE_MINTER_ENTRY_POINT_NAME,
+        CONTRACT_VERSION_ENTRY_POINT_NAME, COUNT, CREATE_STREAM_ENTRY_POINT_NAME, DATA,
+        DECIMALS_ENTRY_POINT_NAME, DECREASE_ALLOWANCE_ENTRY_POINT_NAME, DEPOSIT, DESCRIPTION,
+        DETECT_TRANSFER_RESTRICTION_ENTRY_POINT_NAME, DURATION, ENABLE_MINT_BURN, EPOCH_DURATION,
+        EPOCH_LIMIT, EVENTS_MODE, EXECUTE_HOLD_ENTRY_POINT_NAME, EXPIRATION, EXPIRY,
+        FINALIZE_RECOVERY_ENTRY_POINT_NAME, FORCE_TRANSFER_ENTRY_POINT_NAME,
+        FREEZE_ENTRY_POINT_NAME, FROM, GET_EVENTS_ENTRY_POINT_NAME, HASHLOCK, HOLDER,
+        HOLD_ENTRY_POINT_NAME, HOLD_STATUS_ENTRY_POINT_NAME, HTLC_LOCK_ENTRY_POINT_NAME,
+        HTLC_REDEEM_ENTRY_POINT_NAME, HTLC_REFUND_ENTRY_POINT_NAME, ICON_URL,
+        INCREASE_ALLOWANCE_ENTRY_POINT_NAME, INITIAL_BALANCES, INITIATE_RECOVERY_ENTRY_POINT_NAME,
+        INIT_DISTRIBUTE_ENTRY_POINT_NAME, INIT_ENTRY_POINT_NAME, IS_OPERATOR_FOR_ENTRY_POINT_NAME,
+        IS_WHITELISTED_ENTRY_POINT_NAME, LOCKED_BALANCE_OF_ENTRY_POINT_NAME, LOCK_ID, LOST,
+        MESSAGE_FOR_TRANSFER_RESTRICTION_ENTRY_POINT_NAME, METADATA, MINTER,
+        MINTERS_ENTRY_POINT_NAME, MINTER_ALLOWANCE_ENTRY_POINT_NAME, MINT_ENTRY_POINT_NAME,
+        MINT_LOCKED_ENTRY_POINT_NAME, MINT_QUORUM_THRESHOLD, NAME_ENTRY_POINT_NAME, NEW_OWNER,
+        OPERATION_ID, OPERATOR, OPERATOR_SEND_ENTRY_POINT_NAME, OWNER, OWNER_SPENDER_PAIRS,
+        PENDING_CLAIMS_ENTRY_POINT_NAME, PENDING_CLAIM_ENTRY_POINT_NAME,
+        PENDING_RECOVERY_ENTRY_POINT_NAME, PREIMAGE, RATE, REASON, RECIPIENT,
+        RECLAIM_ENTRY_POINT_NAME, RELEASABLE_OF_ENTRY_POINT_NAME, RELEASE_HOLD_ENTRY_POINT_NAME,
+        REMAINING_MINT_CAPACITY_ENTRY_POINT_NAME, REMOVE_FROM_WHITELIST_ENTRY_POINT_NAME,
+        REMOVE_MINTER_ENTRY_POINT_NAME, RESTRICTION_CODE, REVOKE_OPERATOR_ENTRY_POINT_NAME,
+        SECURITY_BADGE_OF_ENTRY_POINT_NAME, SEND_CLAIMABLE_ENTRY_POINT_NAME,
+        SET_METADATA_ENTRY_POINT_NAME, SET_MINT_RATE_LIMIT_ENTRY_POINT_NAME,
+        SET_MODALITIES_ENTRY_POINT_NAME, SET_QUORUM_ENTRY_POINT_NAME, SET_RATE_ENTRY_POINT_NAME,
+        SET_TRANSFER_ALLOWED_ENTRY_POINT_NAME, SPENDER, START, STOP, STREAM_ID,
+        SUPPORTED_INTERFACES_ENTRY_POINT_NAME, SYMBOL_ENTRY_POINT_NAME, TIMELOCK, TO,
+        TOKEN_INFO_ENTRY_POINT_NAME, TOTAL_SUPPLY_ENTRY_POINT_NAME, TRANSFER_ENTRY_POINT_NAME,
+        TRANSFER_FROM_ENTRY_POINT_NAME, TRANSFER_ID, TRY_TRANSFER_ENTRY_POINT_NAME,
+        TRY_TRANSFER_FROM_ENTRY_POINT_NAME, UNFREEZE_ENTRY_POINT_NAME, WEBSITE,
+        WITHDRAWABLE_FROM_STREAM_ENTRY_POINT_NAME, WITHDRAW_FROM_STREAM_ENTRY_POINT_NAME,
+    },
+    utils::SecurityBadge,
 };
 
 /// Returns the `name` entry point.
@@ -193,6 +227,8 @@ pub fn change_security() -> EntryPoint {
             - "admin_list" : Vec<Key>
             - "mint_and_burn_list" : Vec<Key>
             - "minter_list" : Vec<Key>
+            - "master_minter_list" : Vec<Key>
+            - "kyc_operator_list" : Vec<Key>
             - "burner_list" : Vec<Key>
             - "none_list" : Vec<Key>
             */
@@ -203,6 +239,778 @@ pub fn change_security() -> EntryPoint {
     )
 }
 
+/// Returns the `approve_action` entry point.
+pub fn approve_action() -> EntryPoint {
+    EntryPoint::new(
+        String::from(APPROVE_ACTION_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACTION_HASH, <[u8; 32]>::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_quorum` entry point.
+pub fn set_quorum() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_QUORUM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADMIN_QUORUM, u8::cl_type()),
+            Parameter::new(MINT_QUORUM_THRESHOLD, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `configure_minter` entry point.
+pub fn configure_minter() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CONFIGURE_MINTER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(MINTER, Key::cl_type()),
+            Parameter::new(ALLOWANCE, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `remove_minter` entry point.
+pub fn remove_minter() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMOVE_MINTER_ENTRY_POINT_NAME),
+        vec![Parameter::new(MINTER, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `minter_allowance` entry point.
+pub fn minter_allowance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINTER_ALLOWANCE_ENTRY_POINT_NAME),
+        vec![Parameter::new(MINTER, Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_mint_rate_limit` entry point.
+pub fn set_mint_rate_limit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_MINT_RATE_LIMIT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(EPOCH_DURATION, u64::cl_type()),
+            Parameter::new(EPOCH_LIMIT, U256::cl_type()),
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "minter" : Key
+            */
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `remaining_mint_capacity` entry point.
+pub fn remaining_mint_capacity() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMAINING_MINT_CAPACITY_ENTRY_POINT_NAME),
+        vec![Parameter::new(MINTER, Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `freeze` entry point.
+pub fn freeze() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FREEZE_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `unfreeze` entry point.
+pub fn unfreeze() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNFREEZE_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `detect_transfer_restriction` entry point.
+pub fn detect_transfer_restriction() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DETECT_TRANSFER_RESTRICTION_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(FROM, Key::cl_type()),
+            Parameter::new(TO, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        u8::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `message_for_transfer_restriction` entry point.
+pub fn message_for_transfer_restriction() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MESSAGE_FOR_TRANSFER_RESTRICTION_ENTRY_POINT_NAME),
+        vec![Parameter::new(RESTRICTION_CODE, u8::cl_type())],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `add_to_whitelist` entry point.
+pub fn add_to_whitelist() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ADD_TO_WHITELIST_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNTS, Vec::<Key>::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `remove_from_whitelist` entry point.
+pub fn remove_from_whitelist() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMOVE_FROM_WHITELIST_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNTS, Vec::<Key>::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `is_whitelisted` entry point.
+pub fn is_whitelisted() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_WHITELISTED_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `mint_locked` entry point.
+pub fn mint_locked() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINT_LOCKED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(CLIFF, u64::cl_type()),
+            Parameter::new(DURATION, u64::cl_type()),
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "start" : u64
+            */
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `locked_balance_of` entry point.
+pub fn locked_balance_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(LOCKED_BALANCE_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS, Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `releasable_of` entry point.
+pub fn releasable_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RELEASABLE_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS, Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `force_transfer` entry point.
+pub fn force_transfer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FORCE_TRANSFER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(FROM, Key::cl_type()),
+            Parameter::new(TO, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(REASON, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `clawback` entry point.
+pub fn clawback() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CLAWBACK_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(FROM, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "reason" : String
+            */
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `initiate_recovery` entry point.
+pub fn initiate_recovery() -> EntryPoint {
+    EntryPoint::new(
+        String::from(INITIATE_RECOVERY_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(LOST, Key::cl_type()),
+            Parameter::new(NEW_OWNER, Key::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `cancel_recovery` entry point.
+pub fn cancel_recovery() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_RECOVERY_ENTRY_POINT_NAME),
+        vec![Parameter::new(LOST, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `finalize_recovery` entry point.
+pub fn finalize_recovery() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FINALIZE_RECOVERY_ENTRY_POINT_NAME),
+        vec![Parameter::new(LOST, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `pending_recovery` entry point.
+pub fn pending_recovery() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PENDING_RECOVERY_ENTRY_POINT_NAME),
+        vec![Parameter::new(LOST, Key::cl_type())],
+        Option::<(Key, u64)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `hold` entry point.
+pub fn hold() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HOLD_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OPERATION_ID, String::cl_type()),
+            Parameter::new(FROM, Key::cl_type()),
+            Parameter::new(TO, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(EXPIRATION, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `execute_hold` entry point.
+pub fn execute_hold() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EXECUTE_HOLD_ENTRY_POINT_NAME),
+        vec![Parameter::new(OPERATION_ID, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `release_hold` entry point.
+pub fn release_hold() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RELEASE_HOLD_ENTRY_POINT_NAME),
+        vec![Parameter::new(OPERATION_ID, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `balance_on_hold` entry point.
+pub fn balance_on_hold() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCE_ON_HOLD_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `hold_status` entry point.
+pub fn hold_status() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HOLD_STATUS_ENTRY_POINT_NAME),
+        vec![Parameter::new(OPERATION_ID, String::cl_type())],
+        u8::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `authorize_operator` entry point.
+pub fn authorize_operator() -> EntryPoint {
+    EntryPoint::new(
+        String::from(AUTHORIZE_OPERATOR_ENTRY_POINT_NAME),
+        vec![Parameter::new(OPERATOR, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `revoke_operator` entry point.
+pub fn revoke_operator() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REVOKE_OPERATOR_ENTRY_POINT_NAME),
+        vec![Parameter::new(OPERATOR, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `is_operator_for` entry point.
+pub fn is_operator_for() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_OPERATOR_FOR_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OPERATOR, Key::cl_type()),
+            Parameter::new(HOLDER, Key::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `operator_send` entry point.
+pub fn operator_send() -> EntryPoint {
+    EntryPoint::new(
+        String::from(OPERATOR_SEND_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(HOLDER, Key::cl_type()),
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(DATA, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_transfer_allowed` entry point.
+pub fn set_transfer_allowed() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_TRANSFER_ALLOWED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ACCOUNT, Key::cl_type()),
+            Parameter::new(ALLOWED, bool::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_rate` entry point.
+pub fn set_rate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_RATE_ENTRY_POINT_NAME),
+        vec![Parameter::new(RATE, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `send_claimable` entry point.
+pub fn send_claimable() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SEND_CLAIMABLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(EXPIRY, u64::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `claim` entry point.
+pub fn claim() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CLAIM_ENTRY_POINT_NAME),
+        vec![Parameter::new(TRANSFER_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `reclaim` entry point.
+pub fn reclaim() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RECLAIM_ENTRY_POINT_NAME),
+        vec![Parameter::new(TRANSFER_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `pending_claims` entry point.
+pub fn pending_claims() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PENDING_CLAIMS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(START, u32::cl_type()),
+            Parameter::new(COUNT, u32::cl_type()),
+        ],
+        Vec::<u64>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `pending_claim` entry point.
+pub fn pending_claim() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PENDING_CLAIM_ENTRY_POINT_NAME),
+        vec![Parameter::new(TRANSFER_ID, u64::cl_type())],
+        Option::<(Key, U256, u64)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `create_stream` entry point.
+pub fn create_stream() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CREATE_STREAM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(DEPOSIT, U256::cl_type()),
+            Parameter::new(START, u64::cl_type()),
+            Parameter::new(STOP, u64::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `withdraw_from_stream` entry point.
+pub fn withdraw_from_stream() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WITHDRAW_FROM_STREAM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(STREAM_ID, u64::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `cancel_stream` entry point.
+pub fn cancel_stream() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_STREAM_ENTRY_POINT_NAME),
+        vec![Parameter::new(STREAM_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `withdrawable_from_stream` entry point.
+pub fn withdrawable_from_stream() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WITHDRAWABLE_FROM_STREAM_ENTRY_POINT_NAME),
+        vec![Parameter::new(STREAM_ID, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `htlc_lock` entry point.
+pub fn htlc_lock() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HTLC_LOCK_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(HASHLOCK, <[u8; 32]>::cl_type()),
+            Parameter::new(TIMELOCK, u64::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `htlc_redeem` entry point.
+pub fn htlc_redeem() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HTLC_REDEEM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(LOCK_ID, u64::cl_type()),
+            Parameter::new(PREIMAGE, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `htlc_refund` entry point.
+pub fn htlc_refund() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HTLC_REFUND_ENTRY_POINT_NAME),
+        vec![Parameter::new(LOCK_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_modalities` entry point.
+pub fn set_modalities() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_MODALITIES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(EVENTS_MODE, u8::cl_type()),
+            Parameter::new(ENABLE_MINT_BURN, u8::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_events` entry point.
+pub fn get_events() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_EVENTS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(START, u32::cl_type()),
+            Parameter::new(COUNT, u32::cl_type()),
+        ],
+        Vec::<BTreeMap<String, String>>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `init_distribute` entry point.
+pub fn init_distribute() -> EntryPoint {
+    EntryPoint::new(
+        String::from(INIT_DISTRIBUTE_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            INITIAL_BALANCES,
+            Vec::<(Key, U256)>::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_metadata` entry point.
+pub fn set_metadata() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_METADATA_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(DESCRIPTION, String::cl_type()),
+            Parameter::new(ICON_URL, String::cl_type()),
+            Parameter::new(WEBSITE, String::cl_type()),
+            Parameter::new(METADATA, BTreeMap::<String, String>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `token_info` entry point.
+pub fn token_info() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOKEN_INFO_ENTRY_POINT_NAME),
+        Vec::new(),
+        <(BTreeMap<String, String>, BTreeMap<String, String>)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `supported_interfaces` entry point.
+pub fn supported_interfaces() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SUPPORTED_INTERFACES_ENTRY_POINT_NAME),
+        Vec::new(),
+        Vec::<String>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `contract_version` entry point.
+pub fn contract_version() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CONTRACT_VERSION_ENTRY_POINT_NAME),
+        Vec::new(),
+        <(String, u32)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `security_badge_of` entry point.
+pub fn security_badge_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SECURITY_BADGE_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS, Key::cl_type())],
+        Option::<SecurityBadge>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `admins` entry point.
+pub fn admins() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ADMINS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(START, u32::cl_type()),
+            Parameter::new(COUNT, u32::cl_type()),
+        ],
+        Vec::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `minters` entry point.
+pub fn minters() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINTERS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(START, u32::cl_type()),
+            Parameter::new(COUNT, u32::cl_type()),
+        ],
+        Vec::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `try_transfer` entry point.
+pub fn try_transfer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRY_TRANSFER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        Result::<(), u32>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `try_transfer_from` entry point.
+pub fn try_transfer_from() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRY_TRANSFER_FROM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER, Key::cl_type()),
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        Result::<(), u32>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `balances_of` entry point.
+pub fn balances_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCES_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESSES, Vec::<Key>::cl_type())],
+        Vec::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `allowances_of` entry point.
+pub fn allowances_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ALLOWANCES_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            OWNER_SPENDER_PAIRS,
+            Vec::<(Key, Key)>::cl_type(),
+        )],
+        Vec::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `init` entry point.
 pub fn init() -> EntryPoint {
     EntryPoint::new(
@@ -232,5 +1040,65 @@ pub fn generate_entry_points() -> EntryPoints {
     entry_points.add_entry_point(change_security());
     entry_points.add_entry_point(burn());
     entry_points.add_entry_point(mint());
+    entry_points.add_entry_point(approve_action());
+    entry_points.add_entry_point(set_quorum());
+    entry_points.add_entry_point(configure_minter());
+    entry_points.add_entry_point(remove_minter());
+    entry_points.add_entry_point(minter_allowance());
+    entry_points.add_entry_point(set_mint_rate_limit());
+    entry_points.add_entry_point(remaining_mint_capacity());
+    entry_points.add_entry_point(freeze());
+    entry_points.add_entry_point(unfreeze());
+    entry_points.add_entry_point(detect_transfer_restriction());
+    entry_points.add_entry_point(message_for_transfer_restriction());
+    entry_points.add_entry_point(add_to_whitelist());
+    entry_points.add_entry_point(remove_from_whitelist());
+    entry_points.add_entry_point(is_whitelisted());
+    entry_points.add_entry_point(mint_locked());
+    entry_points.add_entry_point(locked_balance_of());
+    entry_points.add_entry_point(releasable_of());
+    entry_points.add_entry_point(force_transfer());
+    entry_points.add_entry_point(clawback());
+    entry_points.add_entry_point(initiate_recovery());
+    entry_points.add_entry_point(cancel_recovery());
+    entry_points.add_entry_point(finalize_recovery());
+    entry_points.add_entry_point(pending_recovery());
+    entry_points.add_entry_point(hold());
+    entry_points.add_entry_point(execute_hold());
+    entry_points.add_entry_point(release_hold());
+    entry_points.add_entry_point(balance_on_hold());
+    entry_points.add_entry_point(hold_status());
+    entry_points.add_entry_point(authorize_operator());
+    entry_points.add_entry_point(revoke_operator());
+    entry_points.add_entry_point(is_operator_for());
+    entry_points.add_entry_point(operator_send());
+    entry_points.add_entry_point(set_transfer_allowed());
+    entry_points.add_entry_point(set_rate());
+    entry_points.add_entry_point(send_claimable());
+    entry_points.add_entry_point(claim());
+    entry_points.add_entry_point(reclaim());
+    entry_points.add_entry_point(pending_claims());
+    entry_points.add_entry_point(pending_claim());
+    entry_points.add_entry_point(create_stream());
+    entry_points.add_entry_point(withdraw_from_stream());
+    entry_points.add_entry_point(cancel_stream());
+    entry_points.add_entry_point(withdrawable_from_stream());
+    entry_points.add_entry_point(htlc_lock());
+    entry_points.add_entry_point(htlc_redeem());
+    entry_points.add_entry_point(htlc_refund());
+    entry_points.add_entry_point(set_modalities());
+    entry_points.add_entry_point(get_events());
+    entry_points.add_entry_point(init_distribute());
+    entry_points.add_entry_point(set_metadata());
+    entry_points.add_entry_point(token_info());
+    entry_points.add_entry_point(supported_interfaces());
+    entry_points.add_entry_point(contract_version());
+    entry_points.add_entry_point(security_badge_of());
+    entry_points.add_entry_point(admins());
+    entry_points.add_entry_point(minters());
+    entry_points.add_entry_point(try_transfer());
+    entry_points.add_entry_point(try_transfer_from());
+    entry_points.add_entry_point(balances_of());
+    entry_points.add_entry_point(allowances_of());
     entry_points
 }
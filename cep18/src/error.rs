@@ -47,6 +47,124 @@ pub enum Cep18Error {
     MintBurnDisabled = 60016,
     CannotTargetSelfUser = 60017,
     InvalidBurnTarget = 60018,
+    /// Not enough admins approved the requested action.
+    QuorumNotReached = 60019,
+    /// The caller already approved the requested action.
+    ActionAlreadyApproved = 60020,
+    /// The admin quorum provided is invalid.
+    InvalidQuorum = 60021,
+    /// The minter does not have enough minting allowance.
+    MinterAllowanceExceeded = 60022,
+    /// The list of accounts that can configure minters is invalid.
+    InvalidMasterMinterList = 60023,
+    /// The amount minted during the current epoch would exceed the rate limit.
+    MintRateLimitExceeded = 60024,
+    /// The sender account is frozen.
+    SenderFrozen = 60025,
+    /// The recipient account is frozen.
+    RecipientFrozen = 60026,
+    /// An invalid transfer mode was specified.
+    InvalidTransferMode = 60027,
+    /// The sender account is not whitelisted.
+    SenderNotWhitelisted = 60028,
+    /// The recipient account is not whitelisted.
+    RecipientNotWhitelisted = 60029,
+    /// The list of accounts that can manage the whitelist is invalid.
+    InvalidKycOperatorList = 60030,
+    /// The amount exceeds the unlocked part of the balance.
+    AmountLocked = 60031,
+    /// The vesting schedule provided is invalid.
+    InvalidVestingSchedule = 60032,
+    /// The enforcement mode provided is invalid.
+    InvalidEnforcementMode = 60033,
+    /// Forced transfers and clawbacks are disabled for this token.
+    EnforcementDisabled = 60034,
+    /// The list of accounts that can force transfers is invalid.
+    InvalidEnforcerList = 60035,
+    /// The recovery delay provided is invalid.
+    InvalidRecoveryDelay = 60036,
+    /// A recovery of the account is already pending.
+    RecoveryAlreadyPending = 60037,
+    /// No recovery of the account is pending.
+    NoPendingRecovery = 60038,
+    /// The challenge window of the recovery is not over yet.
+    RecoveryChallengePeriod = 60039,
+    /// The list of accounts that can order holds is invalid.
+    InvalidNotaryList = 60040,
+    /// A hold already exists for the operation id.
+    HoldAlreadyExists = 60041,
+    /// No ordered hold exists for the operation id.
+    HoldNotFound = 60042,
+    /// The expiration of the hold is not in the future.
+    InvalidHoldExpiration = 60043,
+    /// The amount exceeds the part of the balance which is not on hold.
+    AmountOnHold = 60044,
+    /// The list of default operators is invalid.
+    InvalidDefaultOperators = 60045,
+    /// The caller is not an operator for the holder.
+    NotOperatorForHolder = 60046,
+    /// The transferability provided is invalid.
+    InvalidTransferability = 60047,
+    /// The token is non-transferable.
+    NonTransferable = 60048,
+    /// The list of accounts exempted from non-transferability is invalid.
+    InvalidTransferAllowList = 60049,
+    /// The interest mode provided is invalid.
+    InvalidInterestMode = 60050,
+    /// Interest accrual is disabled for this token.
+    InterestDisabled = 60051,
+    /// The interest rate provided is invalid.
+    InvalidInterestRate = 60052,
+    /// No pending claim exists for the transfer id.
+    ClaimNotFound = 60053,
+    /// The expiry of the claimable transfer is not in the future.
+    InvalidClaimExpiry = 60054,
+    /// The claimable transfer cannot be reclaimed before its expiry.
+    ClaimNotExpired = 60055,
+    /// The deposit of the stream is zero or its stop is not after its start.
+    InvalidStreamSchedule = 60056,
+    /// No open stream exists for the stream id.
+    StreamNotFound = 60057,
+    /// The amount exceeds the part of the stream which can be withdrawn.
+    AmountNotStreamed = 60058,
+    /// The timelock of the HTLC is not in the future.
+    InvalidTimelock = 60059,
+    /// No open HTLC exists for the lock id.
+    HtlcNotFound = 60060,
+    /// The preimage does not match the hashlock of the HTLC.
+    InvalidPreimage = 60061,
+    /// The HTLC cannot be redeemed once its timelock expired.
+    HtlcExpired = 60062,
+    /// The HTLC cannot be refunded before its timelock expired.
+    HtlcNotExpired = 60063,
+    /// The initial balances are invalid or exceed the total supply.
+    InvalidInitialBalances = 60064,
+    /// The initial distribution of the total supply is not complete.
+    InitialDistributionPending = 60065,
+    /// The token name is empty, too long or contains unsupported characters.
+    InvalidName = 60066,
+    /// The token symbol is empty, too long or contains unsupported characters.
+    InvalidSymbol = 60067,
+    /// The token decimals exceed `MAX_DECIMALS`.
+    InvalidDecimals = 60068,
+    /// A key is listed more than once in the security lists.
+    DuplicateListEntry = 60069,
+    /// A listed key is neither an account hash nor a hash.
+    UnsupportedKeyVariant = 60070,
+    /// The installing account already holds a named key of a token with the same name.
+    NamedKeyAlreadyExists = 60071,
+    /// The description, icon URL, website or metadata argument is invalid.
+    InvalidMetadata = 60072,
+    /// The admin quorum exceeds the number of admins.
+    QuorumExceedsAdmins = 60073,
+    /// The balance held in the custody of the contract cannot be moved by this entry point.
+    CustodyKeyRestricted = 60074,
+    /// The amount of a claimable transfer cannot be zero.
+    InvalidClaimAmount = 60075,
+    /// The recipient has reached `MAX_PENDING_CLAIMS` pending claims.
+    TooManyPendingClaims = 60076,
+    /// The reason of a clawback is invalid.
+    InvalidReason = 60077,
 }
 
 impl From<Cep18Error> for ApiError {
@@ -54,3 +172,9 @@ impl From<Cep18Error> for ApiError {
         ApiError::User(error as u16)
     }
 }
+
+impl From<Cep18Error> for u32 {
+    fn from(error: Cep18Error) -> Self {
+        error as u32
+    }
+}
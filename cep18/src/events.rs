@@ -1,13 +1,22 @@
 use core::convert::TryFrom;
 
-use alloc::collections::BTreeMap;
-use casper_contract::unwrap_or_revert::UnwrapOrRevert;
-use casper_types::{Key, U256};
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::Bytes, Key, U256};
 
 use crate::{
-    constants::EVENTS_MODE,
+    constants::{EVENTS, EVENTS_LENGTH, EVENTS_MODE, EVENTS_SCHEMA},
     modalities::EventsMode,
-    utils::{read_from, SecurityBadge},
+    utils::{get_uref, read_from, SecurityBadge},
 };
 
 use casper_event_standard::{emit, Event, Schemas};
@@ -16,13 +25,16 @@ pub fn record_event_dictionary(event: Event) {
     let events_mode: EventsMode =
         EventsMode::try_from(read_from::<u8>(EVENTS_MODE)).unwrap_or_revert();
 
-    match events_mode {
-        EventsMode::NoEvents => {}
-        EventsMode::CES => ces(event),
+    if events_mode.records_native() {
+        native(&event);
+    }
+    if events_mode.records_ces() {
+        ces(event);
     }
 }
 
 pub enum Event {
+    Init(Init),
     Mint(Mint),
     Burn(Burn),
     SetAllowance(SetAllowance),
@@ -31,6 +43,52 @@ pub enum Event {
     Transfer(Transfer),
     TransferFrom(TransferFrom),
     ChangeSecurity(ChangeSecurity),
+    ApproveAction(ApproveAction),
+    ChangeQuorum(ChangeQuorum),
+    MinterConfigured(MinterConfigured),
+    MinterRemoved(MinterRemoved),
+    Freeze(Freeze),
+    Unfreeze(Unfreeze),
+    AddToWhitelist(AddToWhitelist),
+    RemoveFromWhitelist(RemoveFromWhitelist),
+    LockCreated(LockCreated),
+    ForcedTransfer(ForcedTransfer),
+    Clawback(Clawback),
+    RecoveryInitiated(RecoveryInitiated),
+    RecoveryCancelled(RecoveryCancelled),
+    RecoveryFinalized(RecoveryFinalized),
+    HoldCreated(HoldCreated),
+    HoldExecuted(HoldExecuted),
+    HoldReleased(HoldReleased),
+    AuthorizedOperator(AuthorizedOperator),
+    RevokedOperator(RevokedOperator),
+    Sent(Sent),
+    ChangeTransferAllowList(ChangeTransferAllowList),
+    IndexUpdated(IndexUpdated),
+    ClaimableSent(ClaimableSent),
+    Claimed(Claimed),
+    Reclaimed(Reclaimed),
+    StreamCreated(StreamCreated),
+    StreamWithdrawn(StreamWithdrawn),
+    StreamCancelled(StreamCancelled),
+    HtlcLocked(HtlcLocked),
+    HtlcRedeemed(HtlcRedeemed),
+    HtlcRefunded(HtlcRefunded),
+    ChangeModalities(ChangeModalities),
+    MetadataUpdated(MetadataUpdated),
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Init {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub events_mode: u8,
+    pub enable_mint_burn: u8,
+    pub transfer_mode: u8,
+    pub transferability: u8,
+    pub enforcement_mode: u8,
+    pub interest_mode: u8,
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
@@ -89,8 +147,477 @@ pub struct ChangeSecurity {
     pub sec_change_map: BTreeMap<Key, SecurityBadge>,
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ApproveAction {
+    pub approver: Key,
+    pub action_hash: [u8; 32],
+    pub approvals: u8,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ChangeQuorum {
+    pub admin: Key,
+    pub admin_quorum: u8,
+    pub mint_quorum_threshold: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MinterConfigured {
+    pub master_minter: Key,
+    pub minter: Key,
+    pub allowance: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MinterRemoved {
+    pub master_minter: Key,
+    pub minter: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Freeze {
+    pub admin: Key,
+    pub account: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Unfreeze {
+    pub admin: Key,
+    pub account: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AddToWhitelist {
+    pub operator: Key,
+    pub accounts: Vec<Key>,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RemoveFromWhitelist {
+    pub operator: Key,
+    pub accounts: Vec<Key>,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct LockCreated {
+    pub owner: Key,
+    pub amount: U256,
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ForcedTransfer {
+    pub enforcer: Key,
+    pub from: Key,
+    pub to: Key,
+    pub amount: U256,
+    pub reason: String,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Clawback {
+    pub enforcer: Key,
+    pub from: Key,
+    pub amount: U256,
+    pub reason: String,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RecoveryInitiated {
+    pub admin: Key,
+    pub lost: Key,
+    pub new_owner: Key,
+    pub finalize_after: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RecoveryCancelled {
+    pub canceller: Key,
+    pub lost: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RecoveryFinalized {
+    pub admin: Key,
+    pub lost: Key,
+    pub new_owner: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct HoldCreated {
+    pub notary: Key,
+    pub operation_id: String,
+    pub from: Key,
+    pub to: Key,
+    pub amount: U256,
+    pub expiration: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct HoldExecuted {
+    pub notary: Key,
+    pub operation_id: String,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct HoldReleased {
+    pub releaser: Key,
+    pub operation_id: String,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AuthorizedOperator {
+    pub operator: Key,
+    pub holder: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RevokedOperator {
+    pub operator: Key,
+    pub holder: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Sent {
+    pub operator: Key,
+    pub holder: Key,
+    pub recipient: Key,
+    pub amount: U256,
+    pub data: Bytes,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ChangeTransferAllowList {
+    pub admin: Key,
+    pub account: Key,
+    pub allowed: bool,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct IndexUpdated {
+    pub index: U256,
+    pub rate: U256,
+    pub timestamp: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ClaimableSent {
+    pub transfer_id: u64,
+    pub sender: Key,
+    pub recipient: Key,
+    pub amount: U256,
+    pub expiry: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Claimed {
+    pub transfer_id: u64,
+    pub recipient: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Reclaimed {
+    pub transfer_id: u64,
+    pub sender: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct StreamCreated {
+    pub stream_id: u64,
+    pub sender: Key,
+    pub recipient: Key,
+    pub deposit: U256,
+    pub start: u64,
+    pub stop: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct StreamWithdrawn {
+    pub stream_id: u64,
+    pub recipient: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct StreamCancelled {
+    pub stream_id: u64,
+    pub canceller: Key,
+    pub sender_amount: U256,
+    pub recipient_amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct HtlcLocked {
+    pub lock_id: u64,
+    pub sender: Key,
+    pub recipient: Key,
+    pub amount: U256,
+    pub hashlock: [u8; 32],
+    pub timelock: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct HtlcRedeemed {
+    pub lock_id: u64,
+    pub recipient: Key,
+    pub amount: U256,
+    pub preimage: Bytes,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct HtlcRefunded {
+    pub lock_id: u64,
+    pub sender: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ChangeModalities {
+    pub admin: Key,
+    pub events_mode: u8,
+    pub enable_mint_burn: u8,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MetadataUpdated {
+    pub admin: Key,
+    pub description: String,
+    pub icon_url: String,
+    pub website: String,
+    pub metadata: BTreeMap<String, String>,
+}
+
+/// Value of an event field as recorded in the native event log.
+trait ToEventValue {
+    fn to_event_value(&self) -> String;
+}
+
+impl ToEventValue for Key {
+    fn to_event_value(&self) -> String {
+        self.to_formatted_string()
+    }
+}
+
+impl ToEventValue for U256 {
+    fn to_event_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToEventValue for u64 {
+    fn to_event_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToEventValue for u8 {
+    fn to_event_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToEventValue for bool {
+    fn to_event_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToEventValue for String {
+    fn to_event_value(&self) -> String {
+        self.clone()
+    }
+}
+
+impl ToEventValue for Bytes {
+    fn to_event_value(&self) -> String {
+        hex::encode(self)
+    }
+}
+
+impl ToEventValue for [u8; 32] {
+    fn to_event_value(&self) -> String {
+        hex::encode(self)
+    }
+}
+
+impl ToEventValue for Vec<Key> {
+    fn to_event_value(&self) -> String {
+        self.iter()
+            .map(|key| key.to_formatted_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+}
+
+impl ToEventValue for BTreeMap<Key, SecurityBadge> {
+    fn to_event_value(&self) -> String {
+        self.iter()
+            .map(|(key, badge)| format!("{}:{}", key.to_formatted_string(), *badge as u8))
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+}
+
+impl ToEventValue for BTreeMap<String, String> {
+    fn to_event_value(&self) -> String {
+        self.iter()
+            .map(|(key, value)| format!("{}:{}", key, value))
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+}
+
+/// Fields of an event as recorded in the native event log.
+trait NativeFields {
+    fn native_fields(&self) -> Vec<(&'static str, String)>;
+}
+
+macro_rules! impl_native_fields {
+    ($($event:ident { $($field:ident),* },)*) => {
+        $(
+            impl NativeFields for $event {
+                fn native_fields(&self) -> Vec<(&'static str, String)> {
+                    vec![$((stringify!($field), self.$field.to_event_value())),*]
+                }
+            }
+        )*
+    };
+}
+
+impl_native_fields! {
+    Init {
+        name,
+        symbol,
+        decimals,
+        events_mode,
+        enable_mint_burn,
+        transfer_mode,
+        transferability,
+        enforcement_mode,
+        interest_mode
+    },
+    Mint { recipient, amount },
+    Burn { owner, amount },
+    SetAllowance { owner, spender, allowance },
+    IncreaseAllowance { owner, spender, allowance, inc_by },
+    DecreaseAllowance { owner, spender, allowance, decr_by },
+    Transfer { sender, recipient, amount },
+    TransferFrom { spender, owner, recipient, amount },
+    ChangeSecurity { admin, sec_change_map },
+    ApproveAction { approver, action_hash, approvals },
+    ChangeQuorum { admin, admin_quorum, mint_quorum_threshold },
+    MinterConfigured { master_minter, minter, allowance },
+    MinterRemoved { master_minter, minter },
+    Freeze { admin, account },
+    Unfreeze { admin, account },
+    AddToWhitelist { operator, accounts },
+    RemoveFromWhitelist { operator, accounts },
+    LockCreated { owner, amount, start, cliff, duration },
+    ForcedTransfer { enforcer, from, to, amount, reason },
+    Clawback { enforcer, from, amount, reason },
+    RecoveryInitiated { admin, lost, new_owner, finalize_after },
+    RecoveryCancelled { canceller, lost },
+    RecoveryFinalized { admin, lost, new_owner, amount },
+    HoldCreated { notary, operation_id, from, to, amount, expiration },
+    HoldExecuted { notary, operation_id },
+    HoldReleased { releaser, operation_id },
+    AuthorizedOperator { operator, holder },
+    RevokedOperator { operator, holder },
+    Sent { operator, holder, recipient, amount, data },
+    ChangeTransferAllowList { admin, account, allowed },
+    IndexUpdated { index, rate, timestamp },
+    ClaimableSent { transfer_id, sender, recipient, amount, expiry },
+    Claimed { transfer_id, recipient, amount },
+    Reclaimed { transfer_id, sender, amount },
+    StreamCreated { stream_id, sender, recipient, deposit, start, stop },
+    StreamWithdrawn { stream_id, recipient, amount },
+    StreamCancelled { stream_id, canceller, sender_amount, recipient_amount },
+    HtlcLocked { lock_id, sender, recipient, amount, hashlock, timelock },
+    HtlcRedeemed { lock_id, recipient, amount, preimage },
+    HtlcRefunded { lock_id, sender, amount },
+    ChangeModalities { admin, events_mode, enable_mint_burn },
+    MetadataUpdated { admin, description, icon_url, website, metadata },
+}
+
+/// Appends an event to the `events` dictionary as a map of its stringified fields, along with its
+/// type, the block time and its sequence number, following the CEP-47 event log.
+fn native(event: &Event) {
+    let (event_type, fields) = match event {
+        Event::Init(ev) => ("Init", ev.native_fields()),
+        Event::Mint(ev) => ("Mint", ev.native_fields()),
+        Event::Burn(ev) => ("Burn", ev.native_fields()),
+        Event::SetAllowance(ev) => ("SetAllowance", ev.native_fields()),
+        Event::IncreaseAllowance(ev) => ("IncreaseAllowance", ev.native_fields()),
+        Event::DecreaseAllowance(ev) => ("DecreaseAllowance", ev.native_fields()),
+        Event::Transfer(ev) => ("Transfer", ev.native_fields()),
+        Event::TransferFrom(ev) => ("TransferFrom", ev.native_fields()),
+        Event::ChangeSecurity(ev) => ("ChangeSecurity", ev.native_fields()),
+        Event::ApproveAction(ev) => ("ApproveAction", ev.native_fields()),
+        Event::ChangeQuorum(ev) => ("ChangeQuorum", ev.native_fields()),
+        Event::MinterConfigured(ev) => ("MinterConfigured", ev.native_fields()),
+        Event::MinterRemoved(ev) => ("MinterRemoved", ev.native_fields()),
+        Event::Freeze(ev) => ("Freeze", ev.native_fields()),
+        Event::Unfreeze(ev) => ("Unfreeze", ev.native_fields()),
+        Event::AddToWhitelist(ev) => ("AddToWhitelist", ev.native_fields()),
+        Event::RemoveFromWhitelist(ev) => ("RemoveFromWhitelist", ev.native_fields()),
+        Event::LockCreated(ev) => ("LockCreated", ev.native_fields()),
+        Event::ForcedTransfer(ev) => ("ForcedTransfer", ev.native_fields()),
+        Event::Clawback(ev) => ("Clawback", ev.native_fields()),
+        Event::RecoveryInitiated(ev) => ("RecoveryInitiated", ev.native_fields()),
+        Event::RecoveryCancelled(ev) => ("RecoveryCancelled", ev.native_fields()),
+        Event::RecoveryFinalized(ev) => ("RecoveryFinalized", ev.native_fields()),
+        Event::HoldCreated(ev) => ("HoldCreated", ev.native_fields()),
+        Event::HoldExecuted(ev) => ("HoldExecuted", ev.native_fields()),
+        Event::HoldReleased(ev) => ("HoldReleased", ev.native_fields()),
+        Event::AuthorizedOperator(ev) => ("AuthorizedOperator", ev.native_fields()),
+        Event::RevokedOperator(ev) => ("RevokedOperator", ev.native_fields()),
+        Event::Sent(ev) => ("Sent", ev.native_fields()),
+        Event::ChangeTransferAllowList(ev) => ("ChangeTransferAllowList", ev.native_fields()),
+        Event::IndexUpdated(ev) => ("IndexUpdated", ev.native_fields()),
+        Event::ClaimableSent(ev) => ("ClaimableSent", ev.native_fields()),
+        Event::Claimed(ev) => ("Claimed", ev.native_fields()),
+        Event::Reclaimed(ev) => ("Reclaimed", ev.native_fields()),
+        Event::StreamCreated(ev) => ("StreamCreated", ev.native_fields()),
+        Event::StreamWithdrawn(ev) => ("StreamWithdrawn", ev.native_fields()),
+        Event::StreamCancelled(ev) => ("StreamCancelled", ev.native_fields()),
+        Event::HtlcLocked(ev) => ("HtlcLocked", ev.native_fields()),
+        Event::HtlcRedeemed(ev) => ("HtlcRedeemed", ev.native_fields()),
+        Event::HtlcRefunded(ev) => ("HtlcRefunded", ev.native_fields()),
+        Event::ChangeModalities(ev) => ("ChangeModalities", ev.native_fields()),
+        Event::MetadataUpdated(ev) => ("MetadataUpdated", ev.native_fields()),
+    };
+
+    let events_length_uref = get_uref(EVENTS_LENGTH);
+    let sequence: u64 = storage::read(events_length_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    let mut native_event: BTreeMap<String, String> = fields
+        .into_iter()
+        .map(|(field, value)| (String::from(field), value))
+        .collect();
+    native_event.insert(String::from("event_type"), String::from(event_type));
+    native_event.insert(
+        String::from("timestamp"),
+        u64::from(runtime::get_blocktime()).to_string(),
+    );
+    native_event.insert(String::from("sequence"), sequence.to_string());
+    storage::dictionary_put(get_uref(EVENTS), &sequence.to_string(), native_event);
+    storage::write(events_length_uref, sequence + 1);
+}
+
+/// Reads the event of a sequence number from the native event log.
+pub fn read_native_event(sequence: u64) -> Option<BTreeMap<String, String>> {
+    storage::dictionary_get(get_uref(EVENTS), &sequence.to_string()).unwrap_or_revert()
+}
+
 fn ces(event: Event) {
     match event {
+        Event::Init(ev) => emit(ev),
         Event::Mint(ev) => emit(ev),
         Event::Burn(ev) => emit(ev),
         Event::SetAllowance(ev) => emit(ev),
@@ -99,15 +626,51 @@ fn ces(event: Event) {
         Event::Transfer(ev) => emit(ev),
         Event::TransferFrom(ev) => emit(ev),
         Event::ChangeSecurity(ev) => emit(ev),
+        Event::ApproveAction(ev) => emit(ev),
+        Event::ChangeQuorum(ev) => emit(ev),
+        Event::MinterConfigured(ev) => emit(ev),
+        Event::MinterRemoved(ev) => emit(ev),
+        Event::Freeze(ev) => emit(ev),
+        Event::Unfreeze(ev) => emit(ev),
+        Event::AddToWhitelist(ev) => emit(ev),
+        Event::RemoveFromWhitelist(ev) => emit(ev),
+        Event::LockCreated(ev) => emit(ev),
+        Event::ForcedTransfer(ev) => emit(ev),
+        Event::Clawback(ev) => emit(ev),
+        Event::RecoveryInitiated(ev) => emit(ev),
+        Event::RecoveryCancelled(ev) => emit(ev),
+        Event::RecoveryFinalized(ev) => emit(ev),
+        Event::HoldCreated(ev) => emit(ev),
+        Event::HoldExecuted(ev) => emit(ev),
+        Event::HoldReleased(ev) => emit(ev),
+        Event::AuthorizedOperator(ev) => emit(ev),
+        Event::RevokedOperator(ev) => emit(ev),
+        Event::Sent(ev) => emit(ev),
+        Event::ChangeTransferAllowList(ev) => emit(ev),
+        Event::IndexUpdated(ev) => emit(ev),
+        Event::ClaimableSent(ev) => emit(ev),
+        Event::Claimed(ev) => emit(ev),
+        Event::Reclaimed(ev) => emit(ev),
+        Event::StreamCreated(ev) => emit(ev),
+        Event::StreamWithdrawn(ev) => emit(ev),
+        Event::StreamCancelled(ev) => emit(ev),
+        Event::HtlcLocked(ev) => emit(ev),
+        Event::HtlcRedeemed(ev) => emit(ev),
+        Event::HtlcRefunded(ev) => emit(ev),
+        Event::ChangeModalities(ev) => emit(ev),
+        Event::MetadataUpdated(ev) => emit(ev),
     }
 }
 
+/// Registers the CES schemas if the contract records CES events and they are not registered yet,
+/// e.g. when switching from `NoEvents` through `set_modalities`.
 pub fn init_events() {
     let events_mode: EventsMode =
         EventsMode::try_from(read_from::<u8>(EVENTS_MODE)).unwrap_or_revert();
 
-    if events_mode == EventsMode::CES {
+    if events_mode.records_ces() && !runtime::has_key(EVENTS_SCHEMA) {
         let schemas = Schemas::new()
+            .with::<Init>()
             .with::<Mint>()
             .with::<Burn>()
             .with::<SetAllowance>()
@@ -115,7 +678,40 @@ pub fn init_events() {
             .with::<DecreaseAllowance>()
             .with::<Transfer>()
             .with::<TransferFrom>()
-            .with::<ChangeSecurity>();
+            .with::<ChangeSecurity>()
+            .with::<ApproveAction>()
+            .with::<ChangeQuorum>()
+            .with::<MinterConfigured>()
+            .with::<MinterRemoved>()
+            .with::<Freeze>()
+            .with::<Unfreeze>()
+            .with::<AddToWhitelist>()
+            .with::<RemoveFromWhitelist>()
+            .with::<LockCreated>()
+            .with::<ForcedTransfer>()
+            .with::<Clawback>()
+            .with::<RecoveryInitiated>()
+            .with::<RecoveryCancelled>()
+            .with::<RecoveryFinalized>()
+            .with::<HoldCreated>()
+            .with::<HoldExecuted>()
+            .with::<HoldReleased>()
+            .with::<AuthorizedOperator>()
+            .with::<RevokedOperator>()
+            .with::<Sent>()
+            .with::<ChangeTransferAllowList>()
+            .with::<IndexUpdated>()
+            .with::<ClaimableSent>()
+            .with::<Claimed>()
+            .with::<Reclaimed>()
+            .with::<StreamCreated>()
+            .with::<StreamWithdrawn>()
+            .with::<StreamCancelled>()
+            .with::<HtlcLocked>()
+            .with::<HtlcRedeemed>()
+            .with::<HtlcRefunded>()
+            .with::<ChangeModalities>()
+            .with::<MetadataUpdated>();
         casper_event_standard::init(schemas);
     }
 }
@@ -0,0 +1,244 @@
+//! Implementation of holds, earmarking part of a balance for a pending settlement.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key, URef, U256,
+};
+
+use crate::{
+    balances::{get_balances_uref, read_balance_from},
+    constants::{ACCOUNT_HOLDS, HOLDS},
+    error::Cep18Error,
+    utils, vesting,
+};
+
+/// Status codes returned by `hold_status`.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HoldStatus {
+    Nonexistent = 0,
+    Ordered = 1,
+    Executed = 2,
+    Released = 3,
+    Expired = 4,
+}
+
+impl HoldStatus {
+    fn from_u8(value: u8) -> Result<Self, bytesrepr::Error> {
+        match value {
+            0 => Ok(HoldStatus::Nonexistent),
+            1 => Ok(HoldStatus::Ordered),
+            2 => Ok(HoldStatus::Executed),
+            3 => Ok(HoldStatus::Released),
+            4 => Ok(HoldStatus::Expired),
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// An amount of the balance of `from` earmarked by `notary` for a transfer to `to`, released
+/// automatically at block time `expiration`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Hold {
+    pub notary: Key,
+    pub from: Key,
+    pub to: Key,
+    pub amount: U256,
+    pub expiration: u64,
+    pub status: HoldStatus,
+}
+
+impl Hold {
+    /// Returns the status of the hold at block time `now`.
+    pub(crate) fn status_at(&self, now: u64) -> HoldStatus {
+        if self.status == HoldStatus::Ordered && now >= self.expiration {
+            return HoldStatus::Expired;
+        }
+        self.status
+    }
+}
+
+impl CLTyped for Hold {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Hold {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.notary.to_bytes()?);
+        result.append(&mut self.from.to_bytes()?);
+        result.append(&mut self.to.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.expiration.to_bytes()?);
+        result.push(self.status as u8);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.notary.serialized_length()
+            + self.from.serialized_length()
+            + self.to.serialized_length()
+            + self.amount.serialized_length()
+            + self.expiration.serialized_length()
+            + 1
+    }
+}
+
+impl FromBytes for Hold {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (notary, remainder) = Key::from_bytes(bytes)?;
+        let (from, remainder) = Key::from_bytes(remainder)?;
+        let (to, remainder) = Key::from_bytes(remainder)?;
+        let (amount, remainder) = U256::from_bytes(remainder)?;
+        let (expiration, remainder) = u64::from_bytes(remainder)?;
+        let (status, remainder) = u8::from_bytes(remainder)?;
+        Ok((
+            Hold {
+                notary,
+                from,
+                to,
+                amount,
+                expiration,
+                status: HoldStatus::from_u8(status)?,
+            },
+            remainder,
+        ))
+    }
+}
+
+/// Creates a dictionary item key for an operation id, hashing it since operation ids are not
+/// bounded in length.
+#[inline]
+fn make_operation_item_key(operation_id: &str) -> String {
+    hex::encode(runtime::blake2b(operation_id.as_bytes()))
+}
+
+/// Creates a dictionary item key for an account.
+#[inline]
+fn make_account_item_key(account: Key) -> String {
+    base64::encode(account.to_bytes().unwrap_or_revert())
+}
+
+/// Getter for the "holds" dictionary URef.
+#[inline]
+pub(crate) fn get_holds_uref() -> URef {
+    utils::get_uref(HOLDS)
+}
+
+/// Reads the hold of an operation.
+pub(crate) fn read_hold_from(holds_uref: URef, operation_id: &str) -> Option<Hold> {
+    storage::dictionary_get(holds_uref, &make_operation_item_key(operation_id)).unwrap_or_revert()
+}
+
+/// Writes the hold of an operation.
+pub(crate) fn write_hold_to(holds_uref: URef, operation_id: &str, hold: Hold) {
+    storage::dictionary_put(holds_uref, &make_operation_item_key(operation_id), hold)
+}
+
+/// Reads the `(operation_id, amount, expiration)` triples of the holds ordered on an account.
+fn read_account_holds(account_holds_uref: URef, account: Key) -> Vec<(String, U256, u64)> {
+    storage::dictionary_get(account_holds_uref, &make_account_item_key(account))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Writes the holds ordered on an account, dropping the expired ones.
+fn write_account_holds(
+    account_holds_uref: URef,
+    account: Key,
+    mut account_holds: Vec<(String, U256, u64)>,
+) {
+    let now = u64::from(runtime::get_blocktime());
+    account_holds.retain(|(_, _, expiration)| *expiration > now);
+    storage::dictionary_put(
+        account_holds_uref,
+        &make_account_item_key(account),
+        account_holds,
+    )
+}
+
+/// Returns the amount of the balance of `account` held by unexpired holds.
+pub(crate) fn balance_on_hold(account: Key) -> U256 {
+    let now = u64::from(runtime::get_blocktime());
+    read_account_holds(utils::get_uref(ACCOUNT_HOLDS), account)
+        .iter()
+        .filter(|(_, _, expiration)| *expiration > now)
+        .fold(U256::zero(), |held, (_, amount, _)| {
+            held.saturating_add(*amount)
+        })
+}
+
+/// Orders a hold under `operation_id`, checking that the spendable balance of `from` covers it.
+pub(crate) fn create_hold(operation_id: &str, hold: Hold) -> Result<(), Cep18Error> {
+    let now = u64::from(runtime::get_blocktime());
+    if hold.expiration <= now {
+        return Err(Cep18Error::InvalidHoldExpiration);
+    }
+    let holds_uref = get_holds_uref();
+    if read_hold_from(holds_uref, operation_id).is_some() {
+        return Err(Cep18Error::HoldAlreadyExists);
+    }
+    let spendable = read_balance_from(get_balances_uref(), hold.from)
+        .saturating_sub(vesting::locked_balance_of(hold.from))
+        .saturating_sub(balance_on_hold(hold.from));
+    if spendable < hold.amount {
+        return Err(Cep18Error::InsufficientBalance);
+    }
+
+    let account_holds_uref = utils::get_uref(ACCOUNT_HOLDS);
+    let mut account_holds = read_account_holds(account_holds_uref, hold.from);
+    account_holds.push((String::from(operation_id), hold.amount, hold.expiration));
+    write_account_holds(account_holds_uref, hold.from, account_holds);
+    write_hold_to(holds_uref, operation_id, hold);
+    Ok(())
+}
+
+/// Closes the ordered hold of `operation_id` with the `Executed` or `Released` status, returning
+/// it so the caller can move the funds.
+pub(crate) fn close_hold(operation_id: &str, status: HoldStatus) -> Result<Hold, Cep18Error> {
+    let now = u64::from(runtime::get_blocktime());
+    let holds_uref = get_holds_uref();
+    let mut hold = read_hold_from(holds_uref, operation_id).ok_or(Cep18Error::HoldNotFound)?;
+    match hold.status_at(now) {
+        HoldStatus::Ordered => {}
+        HoldStatus::Expired if status == HoldStatus::Released => {}
+        _ => return Err(Cep18Error::HoldNotFound),
+    }
+
+    let account_holds_uref = utils::get_uref(ACCOUNT_HOLDS);
+    let mut account_holds = read_account_holds(account_holds_uref, hold.from);
+    account_holds.retain(|(id, _, _)| id != operation_id);
+    write_account_holds(account_holds_uref, hold.from, account_holds);
+    hold.status = status;
+    write_hold_to(holds_uref, operation_id, hold.clone());
+    Ok(hold)
+}
+
+/// Releases every unexpired hold ordered on `account`, returning their operation ids.
+pub(crate) fn release_account_holds(account: Key) -> Vec<String> {
+    let now = u64::from(runtime::get_blocktime());
+    let holds_uref = get_holds_uref();
+    let account_holds_uref = utils::get_uref(ACCOUNT_HOLDS);
+    let mut released = Vec::new();
+    for (operation_id, _, expiration) in read_account_holds(account_holds_uref, account) {
+        if expiration <= now {
+            continue;
+        }
+        if let Some(mut hold) = read_hold_from(holds_uref, &operation_id) {
+            hold.status = HoldStatus::Released;
+            write_hold_to(holds_uref, &operation_id, hold);
+            released.push(operation_id);
+        }
+    }
+    if !released.is_empty() {
+        write_account_holds(account_holds_uref, account, Vec::new());
+    }
+    released
+}
@@ -0,0 +1,160 @@
+//! Implementation of hashed timelock contracts, escrowing tokens until the preimage of a hash is
+//! revealed or a timelock expires.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key, URef, U256,
+};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    constants::{HTLCS, HTLCS_COUNT},
+    error::Cep18Error,
+    utils,
+};
+
+/// An amount escrowed by `sender` for `recipient` until the preimage of `hashlock` is revealed,
+/// which `sender` can take back from block time `timelock`. The contract holds `scaled_amount`,
+/// the amount stored for it in its balance.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Htlc {
+    pub sender: Key,
+    pub recipient: Key,
+    pub amount: U256,
+    pub scaled_amount: U256,
+    pub hashlock: [u8; 32],
+    pub timelock: u64,
+}
+
+impl Htlc {
+    /// Returns whether `preimage` unlocks the hashlock, hashed with blake2b or, for swaps with
+    /// chains using it, sha256.
+    pub(crate) fn is_unlocked_by(&self, preimage: &[u8]) -> bool {
+        runtime::blake2b(preimage) == self.hashlock
+            || <[u8; 32]>::from(Sha256::digest(preimage)) == self.hashlock
+    }
+}
+
+impl CLTyped for Htlc {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Htlc {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.sender.to_bytes()?);
+        result.append(&mut self.recipient.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.scaled_amount.to_bytes()?);
+        result.append(&mut self.hashlock.to_bytes()?);
+        result.append(&mut self.timelock.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.sender.serialized_length()
+            + self.recipient.serialized_length()
+            + self.amount.serialized_length()
+            + self.scaled_amount.serialized_length()
+            + self.hashlock.serialized_length()
+            + self.timelock.serialized_length()
+    }
+}
+
+impl FromBytes for Htlc {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (sender, remainder) = Key::from_bytes(bytes)?;
+        let (recipient, remainder) = Key::from_bytes(remainder)?;
+        let (amount, remainder) = U256::from_bytes(remainder)?;
+        let (scaled_amount, remainder) = U256::from_bytes(remainder)?;
+        let (hashlock, remainder) = <[u8; 32]>::from_bytes(remainder)?;
+        let (timelock, remainder) = u64::from_bytes(remainder)?;
+        Ok((
+            Htlc {
+                sender,
+                recipient,
+                amount,
+                scaled_amount,
+                hashlock,
+                timelock,
+            },
+            remainder,
+        ))
+    }
+}
+
+/// Creates a dictionary item key for a lock id.
+#[inline]
+fn make_dictionary_item_key(lock_id: u64) -> String {
+    hex::encode(lock_id.to_bytes().unwrap_or_revert())
+}
+
+/// Getter for the "htlcs" dictionary URef.
+#[inline]
+pub(crate) fn get_htlcs_uref() -> URef {
+    utils::get_uref(HTLCS)
+}
+
+/// Reads the open HTLC of a lock id.
+pub(crate) fn read_htlc_from(htlcs_uref: URef, lock_id: u64) -> Option<Htlc> {
+    storage::dictionary_get(htlcs_uref, &make_dictionary_item_key(lock_id))
+        .unwrap_or_revert()
+        .flatten()
+}
+
+/// Writes the HTLC of a lock id, `None` closing it.
+fn write_htlc_to(htlcs_uref: URef, lock_id: u64, htlc: Option<Htlc>) {
+    storage::dictionary_put(htlcs_uref, &make_dictionary_item_key(lock_id), htlc)
+}
+
+/// Records an HTLC under a new lock id, returning it. The escrowed amount has to be moved to the
+/// contract by the caller.
+pub(crate) fn create_htlc(htlc: Htlc) -> Result<u64, Cep18Error> {
+    if htlc.timelock <= u64::from(runtime::get_blocktime()) {
+        return Err(Cep18Error::InvalidTimelock);
+    }
+    let htlcs_count_uref = utils::get_uref(HTLCS_COUNT);
+    let lock_id: u64 = storage::read(htlcs_count_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    storage::write(
+        htlcs_count_uref,
+        lock_id.checked_add(1).ok_or(Cep18Error::Overflow)?,
+    );
+    write_htlc_to(get_htlcs_uref(), lock_id, Some(htlc));
+    Ok(lock_id)
+}
+
+/// Closes the HTLC of a lock id with its preimage before the timelock, returning it so the caller
+/// can release the escrowed amount to the recipient.
+pub(crate) fn redeem_htlc(lock_id: u64, preimage: &[u8]) -> Result<Htlc, Cep18Error> {
+    let htlcs_uref = get_htlcs_uref();
+    let htlc = read_htlc_from(htlcs_uref, lock_id).ok_or(Cep18Error::HtlcNotFound)?;
+    if u64::from(runtime::get_blocktime()) >= htlc.timelock {
+        return Err(Cep18Error::HtlcExpired);
+    }
+    if !htlc.is_unlocked_by(preimage) {
+        return Err(Cep18Error::InvalidPreimage);
+    }
+    write_htlc_to(htlcs_uref, lock_id, None);
+    Ok(htlc)
+}
+
+/// Closes the HTLC of a lock id once the timelock expired, returning it so the caller can refund
+/// the escrowed amount to the sender.
+pub(crate) fn refund_htlc(lock_id: u64) -> Result<Htlc, Cep18Error> {
+    let htlcs_uref = get_htlcs_uref();
+    let htlc = read_htlc_from(htlcs_uref, lock_id).ok_or(Cep18Error::HtlcNotFound)?;
+    if u64::from(runtime::get_blocktime()) < htlc.timelock {
+        return Err(Cep18Error::HtlcNotExpired);
+    }
+    write_htlc_to(htlcs_uref, lock_id, None);
+    Ok(htlc)
+}
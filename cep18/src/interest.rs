@@ -0,0 +1,125 @@
+//! Implementation of the interest accrual index.
+//!
+//! When the `interest_mode` modality is `Accruing`, balances and the total supply are stored as
+//! scaled amounts, the nominal amount being the scaled amount multiplied by the accrual index.
+//! The index starts at `INDEX_SCALE` and grows by the per-second `interest_rate`, expressed with
+//! the same 18 decimals precision, since the last accrual. It is persisted lazily whenever a
+//! balance or the total supply is written.
+//!
+//! Credits round scaled amounts down and debits round them up, and transfers credit the recipient
+//! with the scaled amount debited from the sender, so that rounding never creates tokens.
+use core::convert::TryFrom;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::U256;
+
+use crate::{
+    constants::{ACCRUAL_INDEX, INTEREST_MODE, INTEREST_RATE, LAST_ACCRUAL},
+    error::Cep18Error,
+    events::{self, Event, IndexUpdated},
+    modalities::InterestMode,
+    utils,
+};
+
+/// Precision of the accrual index and of the interest rate.
+pub(crate) const INDEX_SCALE: u64 = 1_000_000_000_000_000_000;
+
+/// Returns whether balances accrue interest, according to the `interest_mode` modality.
+pub(crate) fn is_interest_bearing() -> bool {
+    InterestMode::try_from(utils::read_from::<u8>(INTEREST_MODE)).unwrap_or_revert()
+        == InterestMode::Accruing
+}
+
+/// Returns the index grown by `rate` per second between `last_accrual` and `now` milliseconds.
+fn index_at(index: U256, rate: U256, last_accrual: u64, now: u64) -> U256 {
+    let elapsed = U256::from(now.saturating_sub(last_accrual));
+    let accrued = index
+        .checked_mul(rate)
+        .and_then(|accrued| accrued.checked_mul(elapsed))
+        .ok_or(Cep18Error::Overflow)
+        .unwrap_or_revert()
+        / U256::from(1_000u64)
+        / U256::from(INDEX_SCALE);
+    index
+        .checked_add(accrued)
+        .ok_or(Cep18Error::Overflow)
+        .unwrap_or_revert()
+}
+
+/// Returns the accrual index at the current block time.
+pub(crate) fn current_index() -> U256 {
+    index_at(
+        utils::read_from(ACCRUAL_INDEX),
+        utils::read_from(INTEREST_RATE),
+        utils::read_from(LAST_ACCRUAL),
+        u64::from(runtime::get_blocktime()),
+    )
+}
+
+/// Persists the accrual index at the current block time.
+pub(crate) fn accrue() {
+    if !is_interest_bearing() {
+        return;
+    }
+    let now = u64::from(runtime::get_blocktime());
+    let last_accrual: u64 = utils::read_from(LAST_ACCRUAL);
+    if now <= last_accrual {
+        return;
+    }
+    let rate: U256 = utils::read_from(INTEREST_RATE);
+    let index = index_at(utils::read_from(ACCRUAL_INDEX), rate, last_accrual, now);
+    storage::write(utils::get_uref(ACCRUAL_INDEX), index);
+    storage::write(utils::get_uref(LAST_ACCRUAL), now);
+    if !rate.is_zero() {
+        events::record_event_dictionary(Event::IndexUpdated(IndexUpdated {
+            index,
+            rate,
+            timestamp: now,
+        }));
+    }
+}
+
+/// Converts a nominal amount into the scaled amount stored, rounding down.
+pub(crate) fn to_scaled(amount: U256) -> U256 {
+    if !is_interest_bearing() {
+        return amount;
+    }
+    amount
+        .checked_mul(U256::from(INDEX_SCALE))
+        .ok_or(Cep18Error::Overflow)
+        .unwrap_or_revert()
+        / current_index()
+}
+
+/// Converts a nominal amount into the scaled amount stored, rounding up.
+pub(crate) fn to_scaled_ceil(amount: U256) -> U256 {
+    if !is_interest_bearing() {
+        return amount;
+    }
+    let product = amount
+        .checked_mul(U256::from(INDEX_SCALE))
+        .ok_or(Cep18Error::Overflow)
+        .unwrap_or_revert();
+    let index = current_index();
+    let scaled = product / index;
+    if (product % index).is_zero() {
+        scaled
+    } else {
+        scaled + 1
+    }
+}
+
+/// Converts a stored scaled amount into its nominal amount.
+pub(crate) fn to_nominal(scaled: U256) -> U256 {
+    if !is_interest_bearing() {
+        return scaled;
+    }
+    scaled
+        .checked_mul(current_index())
+        .ok_or(Cep18Error::Overflow)
+        .unwrap_or_revert()
+        / U256::from(INDEX_SCALE)
+}
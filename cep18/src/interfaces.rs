@@ -0,0 +1,198 @@
+//! Implementation of the interface discovery.
+//!
+//! An interface is supported when the entry points implementing it are built by
+//! `entry_points::generate_entry_points` and the stored modalities enable it.
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::convert::TryFrom;
+
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+
+use crate::{
+    constants::{
+        ADD_TO_WHITELIST_ENTRY_POINT_NAME, ADMINS_ENTRY_POINT_NAME, ALLOWANCES_OF_ENTRY_POINT_NAME,
+        APPROVE_ACTION_ENTRY_POINT_NAME, AUTHORIZE_OPERATOR_ENTRY_POINT_NAME,
+        BALANCES_OF_ENTRY_POINT_NAME, BURN_ENTRY_POINT_NAME, CHANGE_SECURITY_ENTRY_POINT_NAME,
+        CLAIM_ENTRY_POINT_NAME, CONFIGURE_MINTER_ENTRY_POINT_NAME, CREATE_STREAM_ENTRY_POINT_NAME,
+        DETECT_TRANSFER_RESTRICTION_ENTRY_POINT_NAME, ENABLE_MINT_BURN, ENFORCEMENT_MODE,
+        EVENTS_MODE, FORCE_TRANSFER_ENTRY_POINT_NAME, FREEZE_ENTRY_POINT_NAME,
+        GET_EVENTS_ENTRY_POINT_NAME, HOLD_ENTRY_POINT_NAME, HTLC_LOCK_ENTRY_POINT_NAME,
+        HTLC_REDEEM_ENTRY_POINT_NAME, INITIATE_RECOVERY_ENTRY_POINT_NAME, INTEREST_MODE,
+        MESSAGE_FOR_TRANSFER_RESTRICTION_ENTRY_POINT_NAME, MINTERS_ENTRY_POINT_NAME,
+        MINT_ENTRY_POINT_NAME, MINT_LOCKED_ENTRY_POINT_NAME, OPERATOR_SEND_ENTRY_POINT_NAME,
+        RELEASE_HOLD_ENTRY_POINT_NAME, SECURITY_BADGE_OF_ENTRY_POINT_NAME,
+        SEND_CLAIMABLE_ENTRY_POINT_NAME, SET_METADATA_ENTRY_POINT_NAME,
+        SET_MINT_RATE_LIMIT_ENTRY_POINT_NAME, SET_RATE_ENTRY_POINT_NAME,
+        SET_TRANSFER_ALLOWED_ENTRY_POINT_NAME, TRANSFERABILITY, TRANSFER_ENTRY_POINT_NAME,
+        TRANSFER_MODE, TRY_TRANSFER_ENTRY_POINT_NAME, TRY_TRANSFER_FROM_ENTRY_POINT_NAME,
+        UNFREEZE_ENTRY_POINT_NAME, WITHDRAW_FROM_STREAM_ENTRY_POINT_NAME,
+    },
+    entry_points::generate_entry_points,
+    modalities::{
+        EnforcementMode, EventsMode, InterestMode, MintBurn, TransferMode, Transferability,
+    },
+    utils::read_from,
+};
+
+/// Interface ids paired with the entry points implementing them and whether the stored
+/// modalities enable them.
+fn interfaces() -> Vec<(&'static str, Vec<&'static str>, bool)> {
+    let events_mode = EventsMode::try_from(read_from::<u8>(EVENTS_MODE)).unwrap_or_revert();
+    let mint_burn = MintBurn::try_from(read_from::<u8>(ENABLE_MINT_BURN)).unwrap_or_revert()
+        == MintBurn::MintAndBurn;
+    let whitelist = TransferMode::try_from(read_from::<u8>(TRANSFER_MODE)).unwrap_or_revert()
+        == TransferMode::Whitelist;
+    let non_transferable = Transferability::try_from(read_from::<u8>(TRANSFERABILITY))
+        .unwrap_or_revert()
+        == Transferability::NonTransferable;
+    let enforcement = EnforcementMode::try_from(read_from::<u8>(ENFORCEMENT_MODE))
+        .unwrap_or_revert()
+        != EnforcementMode::Disabled;
+    let interest = InterestMode::try_from(read_from::<u8>(INTEREST_MODE)).unwrap_or_revert()
+        == InterestMode::Accruing;
+
+    vec![
+        ("cep18", vec![TRANSFER_ENTRY_POINT_NAME], true),
+        ("cep18-mintable", vec![MINT_ENTRY_POINT_NAME], mint_burn),
+        ("cep18-burnable", vec![BURN_ENTRY_POINT_NAME], mint_burn),
+        (
+            "cep18-security",
+            vec![CHANGE_SECURITY_ENTRY_POINT_NAME],
+            true,
+        ),
+        (
+            "cep18-role-queries",
+            vec![
+                SECURITY_BADGE_OF_ENTRY_POINT_NAME,
+                ADMINS_ENTRY_POINT_NAME,
+                MINTERS_ENTRY_POINT_NAME,
+            ],
+            true,
+        ),
+        ("cep18-quorum", vec![APPROVE_ACTION_ENTRY_POINT_NAME], true),
+        (
+            "cep18-minter-allowances",
+            vec![CONFIGURE_MINTER_ENTRY_POINT_NAME],
+            mint_burn,
+        ),
+        (
+            "cep18-mint-rate-limits",
+            vec![SET_MINT_RATE_LIMIT_ENTRY_POINT_NAME],
+            mint_burn,
+        ),
+        // Freezes apply to transfers under every modality.
+        (
+            "cep18-freezable",
+            vec![FREEZE_ENTRY_POINT_NAME, UNFREEZE_ENTRY_POINT_NAME],
+            true,
+        ),
+        (
+            "cep18-whitelist",
+            vec![ADD_TO_WHITELIST_ENTRY_POINT_NAME],
+            whitelist,
+        ),
+        (
+            "cep18-soulbound",
+            vec![SET_TRANSFER_ALLOWED_ENTRY_POINT_NAME],
+            non_transferable,
+        ),
+        // Freezes, locks and holds can restrict transfers under every modality.
+        (
+            "erc1404",
+            vec![
+                DETECT_TRANSFER_RESTRICTION_ENTRY_POINT_NAME,
+                MESSAGE_FOR_TRANSFER_RESTRICTION_ENTRY_POINT_NAME,
+            ],
+            true,
+        ),
+        (
+            "cep18-try-transfers",
+            vec![
+                TRY_TRANSFER_ENTRY_POINT_NAME,
+                TRY_TRANSFER_FROM_ENTRY_POINT_NAME,
+            ],
+            true,
+        ),
+        (
+            "cep18-batch-queries",
+            vec![BALANCES_OF_ENTRY_POINT_NAME, ALLOWANCES_OF_ENTRY_POINT_NAME],
+            true,
+        ),
+        (
+            "cep18-vesting",
+            vec![MINT_LOCKED_ENTRY_POINT_NAME],
+            mint_burn,
+        ),
+        (
+            "cep18-enforcement",
+            vec![FORCE_TRANSFER_ENTRY_POINT_NAME],
+            enforcement,
+        ),
+        (
+            "cep18-recovery",
+            vec![INITIATE_RECOVERY_ENTRY_POINT_NAME],
+            true,
+        ),
+        (
+            "cep18-holds",
+            vec![HOLD_ENTRY_POINT_NAME, RELEASE_HOLD_ENTRY_POINT_NAME],
+            true,
+        ),
+        (
+            "cep18-operators",
+            vec![
+                AUTHORIZE_OPERATOR_ENTRY_POINT_NAME,
+                OPERATOR_SEND_ENTRY_POINT_NAME,
+            ],
+            true,
+        ),
+        (
+            "cep18-claims",
+            vec![SEND_CLAIMABLE_ENTRY_POINT_NAME, CLAIM_ENTRY_POINT_NAME],
+            true,
+        ),
+        (
+            "cep18-streams",
+            vec![
+                CREATE_STREAM_ENTRY_POINT_NAME,
+                WITHDRAW_FROM_STREAM_ENTRY_POINT_NAME,
+            ],
+            true,
+        ),
+        (
+            "cep18-htlc",
+            vec![HTLC_LOCK_ENTRY_POINT_NAME, HTLC_REDEEM_ENTRY_POINT_NAME],
+            true,
+        ),
+        ("cep18-interest", vec![SET_RATE_ENTRY_POINT_NAME], interest),
+        ("cep18-metadata", vec![SET_METADATA_ENTRY_POINT_NAME], true),
+        (
+            "ces",
+            vec![TRANSFER_ENTRY_POINT_NAME],
+            events_mode.records_ces(),
+        ),
+        (
+            "cep18-native-events",
+            vec![GET_EVENTS_ENTRY_POINT_NAME],
+            events_mode.records_native(),
+        ),
+    ]
+}
+
+/// Returns the ids of the interfaces supported by the contract.
+pub(crate) fn supported_interfaces() -> Vec<String> {
+    let entry_points = generate_entry_points();
+    interfaces()
+        .into_iter()
+        .filter(|(_, interface_entry_points, enabled)| {
+            *enabled
+                && interface_entry_points
+                    .iter()
+                    .all(|entry_point| entry_points.has_entry_point(entry_point))
+        })
+        .map(|(interface_id, _, _)| interface_id.to_string())
+        .collect()
+}
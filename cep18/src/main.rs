@@ -5,12 +5,27 @@ extern crate alloc;
 
 mod allowances;
 mod balances;
+mod claims;
 pub mod constants;
 pub mod entry_points;
 mod error;
 mod events;
+mod holds;
+mod htlc;
+mod interest;
+mod interfaces;
+mod minters;
 mod modalities;
+mod operators;
+mod quorum;
+mod rate_limits;
+mod recovery;
+mod restrictions;
+mod streams;
 mod utils;
+mod vesting;
+
+use core::convert::TryFrom;
 
 use alloc::{
     collections::BTreeMap,
@@ -21,34 +36,63 @@ use alloc::{
 };
 
 use allowances::{get_allowances_uref, read_allowance_from, write_allowance_to};
-use balances::{get_balances_uref, read_balance_from, transfer_balance, write_balance_to};
+use balances::{get_balances_uref, read_balance_from, transfer_balance};
 use entry_points::generate_entry_points;
 
 use casper_contract::{
     contract_api::{
         runtime::{self, get_caller, get_key, get_named_arg, put_key, revert},
-        storage::{self, dictionary_put},
+        storage,
     },
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    bytesrepr::ToBytes, contracts::NamedKeys, runtime_args, CLValue, Key, RuntimeArgs, U256,
+    account::AccountHash,
+    bytesrepr::{Bytes, ToBytes},
+    contracts::NamedKeys,
+    runtime_args, CLValue, Key, RuntimeArgs, URef, U256,
 };
 
 use constants::{
-    ACCESS_KEY_NAME_PREFIX, ADDRESS, ADMIN_LIST, ALLOWANCES, AMOUNT, BALANCES,
-    CONTRACT_NAME_PREFIX, CONTRACT_VERSION_PREFIX, DECIMALS, ENABLE_MINT_BURN, EVENTS_MODE,
-    HASH_KEY_NAME_PREFIX, INIT_ENTRY_POINT_NAME, MINTER_LIST, NAME, NONE_LIST, OWNER, PACKAGE_HASH,
-    RECIPIENT, SECURITY_BADGES, SPENDER, SYMBOL, TOTAL_SUPPLY,
+    ACCESS_KEY_NAME_PREFIX, ACCOUNT, ACCOUNTS, ACCOUNT_HOLDS, ACCRUAL_INDEX, ACTION_APPROVALS,
+    ACTION_HASH, ADDRESS, ADDRESSES, ADMIN_LIST, ADMIN_QUORUM, ALLOWANCE, ALLOWANCES, ALLOWED,
+    AMOUNT, BALANCES, BURN_ENTRY_POINT_NAME, CANCEL_RECOVERY_ENTRY_POINT_NAME,
+    CHANGE_SECURITY_ENTRY_POINT_NAME, CHUNKED_DISTRIBUTION, CLAIMS, CLAIMS_COUNT, CLIFF,
+    CONTRACT_NAME_PREFIX, CONTRACT_VERSION_PREFIX, COUNT, DATA, DECIMALS, DEFAULT_OPERATORS,
+    DEFAULT_RECOVERY_DELAY, DEPOSIT, DESCRIPTION, DURATION, ENABLE_MINT_BURN, ENFORCEMENT_MODE,
+    ENFORCER_LIST, EPOCH_DURATION, EPOCH_LIMIT, EVENTS, EVENTS_LENGTH, EVENTS_MODE, EXPIRATION,
+    EXPIRY, FINALIZE_RECOVERY_ENTRY_POINT_NAME, FREEZE_ENTRY_POINT_NAME, FROM, FROZEN, HASHLOCK,
+    HASH_KEY_NAME_PREFIX, HOLDER, HOLDS, HTLCS, HTLCS_COUNT, ICON_URL, INITIAL_BALANCES,
+    INITIATE_RECOVERY_ENTRY_POINT_NAME, INIT_DISTRIBUTE_ENTRY_POINT_NAME, INIT_ENTRY_POINT_NAME,
+    INTEREST_MODE, INTEREST_RATE, KYC_OPERATOR_LIST, LAST_ACCRUAL, LOCKS, LOCK_ID, LOST,
+    MASTER_MINTER_LIST, METADATA, MINTER, MINTER_ALLOWANCE, MINTER_EPOCH_USAGE, MINTER_LIST,
+    MINTER_RATE_LIMITS, MINT_ENTRY_POINT_NAME, MINT_EPOCH_USAGE, MINT_LOCKED_ENTRY_POINT_NAME,
+    MINT_QUORUM_THRESHOLD, MINT_RATE_LIMIT, NAME, NEW_OWNER, NONE_LIST, NOTARY_LIST, OPERATION_ID,
+    OPERATOR, OPERATORS, OWNER, OWNER_SPENDER_PAIRS, PACKAGE_HASH, PREIMAGE, RATE, REASON,
+    RECIPIENT, RECIPIENT_CLAIMS, RECOVERIES, RECOVERY_DELAY, RESTRICTION_CODE, ROLE_MEMBERS,
+    ROLE_MEMBER_INDICES, SCHEMA_VERSION, SECURITY_BADGES, SET_METADATA_ENTRY_POINT_NAME,
+    SET_MINT_RATE_LIMIT_ENTRY_POINT_NAME, SET_MODALITIES_ENTRY_POINT_NAME,
+    SET_QUORUM_ENTRY_POINT_NAME, SET_RATE_ENTRY_POINT_NAME, SET_TRANSFER_ALLOWED_ENTRY_POINT_NAME,
+    SPENDER, START, STOP, STORAGE_SCHEMA_VERSION, STREAMS, STREAMS_COUNT, STREAM_ID, SYMBOL,
+    TIMELOCK, TO, TOTAL_SUPPLY, TRANSFERABILITY, TRANSFER_ALLOW_LIST, TRANSFER_ID, TRANSFER_MODE,
+    UNDISTRIBUTED_SUPPLY, UNFREEZE_ENTRY_POINT_NAME, WEBSITE, WHITELIST,
 };
 pub use error::Cep18Error;
 use events::{
-    init_events, Burn, ChangeSecurity, DecreaseAllowance, Event, IncreaseAllowance, Mint,
-    SetAllowance, Transfer, TransferFrom,
+    init_events, AddToWhitelist, ApproveAction, AuthorizedOperator, Burn, ChangeModalities,
+    ChangeQuorum, ChangeSecurity, ChangeTransferAllowList, ClaimableSent, Claimed, Clawback,
+    DecreaseAllowance, Event, ForcedTransfer, Freeze, HoldCreated, HoldExecuted, HoldReleased,
+    HtlcLocked, HtlcRedeemed, HtlcRefunded, IncreaseAllowance, IndexUpdated, Init, LockCreated,
+    MetadataUpdated, Mint, MinterConfigured, MinterRemoved, Reclaimed, RecoveryCancelled,
+    RecoveryFinalized, RecoveryInitiated, RemoveFromWhitelist, RevokedOperator, Sent, SetAllowance,
+    StreamCancelled, StreamCreated, StreamWithdrawn, Transfer, TransferFrom, Unfreeze,
+};
+use modalities::{
+    EnforcementMode, EventsMode, InterestMode, MintBurn, TransferMode, Transferability,
 };
 use utils::{
-    get_immediate_caller_address, get_total_supply_uref, read_from, read_total_supply_from,
-    sec_check, write_total_supply_to, SecurityBadge,
+    get_immediate_caller_address, get_total_supply_uref, read_from, read_scaled_total_supply_from,
+    read_total_supply_from, sec_check, write_scaled_total_supply_to, SecurityBadge,
 };
 
 #[no_mangle]
@@ -68,7 +112,9 @@ pub extern "C" fn decimals() {
 
 #[no_mangle]
 pub extern "C" fn total_supply() {
-    runtime::ret(CLValue::from_t(utils::read_from::<U256>(TOTAL_SUPPLY)).unwrap_or_revert());
+    runtime::ret(
+        CLValue::from_t(read_total_supply_from(get_total_supply_uref())).unwrap_or_revert(),
+    );
 }
 
 #[no_mangle]
@@ -88,6 +134,32 @@ pub extern "C" fn allowance() {
     runtime::ret(CLValue::from_t(val).unwrap_or_revert());
 }
 
+/// Returns the balances of `addresses`, in the same order, looking up the `balances` dictionary
+/// once.
+#[no_mangle]
+pub extern "C" fn balances_of() {
+    let addresses: Vec<Key> = runtime::get_named_arg(ADDRESSES);
+    let balances_uref = get_balances_uref();
+    let balances: Vec<U256> = addresses
+        .into_iter()
+        .map(|address| balances::read_balance_from(balances_uref, address))
+        .collect();
+    runtime::ret(CLValue::from_t(balances).unwrap_or_revert());
+}
+
+/// Returns the allowances of the `(owner, spender)` pairs, in the same order, looking up the
+/// `allowances` dictionary once.
+#[no_mangle]
+pub extern "C" fn allowances_of() {
+    let owner_spender_pairs: Vec<(Key, Key)> = runtime::get_named_arg(OWNER_SPENDER_PAIRS);
+    let allowances_uref = get_allowances_uref();
+    let allowances: Vec<U256> = owner_spender_pairs
+        .into_iter()
+        .map(|(owner, spender)| read_allowance_from(allowances_uref, owner, spender))
+        .collect();
+    runtime::ret(CLValue::from_t(allowances).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn approve() {
     let owner = utils::get_immediate_caller_address().unwrap_or_revert();
@@ -96,6 +168,8 @@ pub extern "C" fn approve() {
         revert(Cep18Error::CannotTargetSelfUser);
     }
     let amount: U256 = runtime::get_named_arg(AMOUNT);
+    abort_recovery(owner);
+    restrictions::check_delegation_allowed(spender).unwrap_or_revert();
     let allowances_uref = get_allowances_uref();
     write_allowance_to(allowances_uref, owner, spender, amount);
     events::record_event_dictionary(Event::SetAllowance(SetAllowance {
@@ -113,6 +187,8 @@ pub extern "C" fn decrease_allowance() {
         revert(Cep18Error::CannotTargetSelfUser);
     }
     let amount: U256 = runtime::get_named_arg(AMOUNT);
+    abort_recovery(owner);
+    restrictions::check_delegation_allowed(spender).unwrap_or_revert();
     let allowances_uref = get_allowances_uref();
     let current_allowance = read_allowance_from(allowances_uref, owner, spender);
     let new_allowance = current_allowance.saturating_sub(amount);
@@ -133,6 +209,8 @@ pub extern "C" fn increase_allowance() {
         revert(Cep18Error::CannotTargetSelfUser);
     }
     let amount: U256 = runtime::get_named_arg(AMOUNT);
+    abort_recovery(owner);
+    restrictions::check_delegation_allowed(spender).unwrap_or_revert();
     let allowances_uref = get_allowances_uref();
     let current_allowance = read_allowance_from(allowances_uref, owner, spender);
     let new_allowance = current_allowance.saturating_add(amount);
@@ -149,47 +227,97 @@ pub extern "C" fn increase_allowance() {
 pub extern "C" fn transfer() {
     let sender = utils::get_immediate_caller_address().unwrap_or_revert();
     let recipient: Key = runtime::get_named_arg(RECIPIENT);
-    if sender == recipient {
-        revert(Cep18Error::CannotTargetSelfUser);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    abort_recovery(sender);
+    transfer_tokens(sender, recipient, amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let spender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    abort_recovery(spender);
+    transfer_tokens_from(spender, owner, recipient, amount).unwrap_or_revert();
+}
+
+/// Same as `transfer`, returning the `Cep18Error` code on failure instead of reverting, for
+/// integrating contracts to fall back within the same deploy.
+#[no_mangle]
+pub extern "C" fn try_transfer() {
+    let sender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let result = transfer_tokens(sender, recipient, amount);
+    if result.is_ok() {
+        abort_recovery(sender);
     }
+    runtime::ret(CLValue::from_t(result.map_err(u32::from)).unwrap_or_revert());
+}
+
+/// Same as `transfer_from`, returning the `Cep18Error` code on failure instead of reverting, for
+/// integrating contracts to fall back within the same deploy.
+#[no_mangle]
+pub extern "C" fn try_transfer_from() {
+    let spender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let owner: Key = runtime::get_named_arg(OWNER);
     let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let result = transfer_tokens_from(spender, owner, recipient, amount);
+    if result.is_ok() {
+        abort_recovery(spender);
+    }
+    runtime::ret(CLValue::from_t(result.map_err(u32::from)).unwrap_or_revert());
+}
 
-    transfer_balance(sender, recipient, amount).unwrap_or_revert();
+/// Transfers `amount` from `sender` to `recipient`, recording a `Transfer` event. Nothing is
+/// written when an error is returned.
+fn transfer_tokens(sender: Key, recipient: Key, amount: U256) -> Result<(), Cep18Error> {
+    if sender == recipient {
+        return Err(Cep18Error::CannotTargetSelfUser);
+    }
+    restrictions::check_transfer_restriction(sender, recipient, amount)?;
+    transfer_balance(sender, recipient, amount)?;
     events::record_event_dictionary(Event::Transfer(Transfer {
         sender,
         recipient,
         amount,
-    }))
+    }));
+    Ok(())
 }
 
-#[no_mangle]
-pub extern "C" fn transfer_from() {
-    let spender = utils::get_immediate_caller_address().unwrap_or_revert();
-    let recipient: Key = runtime::get_named_arg(RECIPIENT);
-    let owner: Key = runtime::get_named_arg(OWNER);
+/// Transfers `amount` from `owner` to `recipient` out of the allowance of `spender`, recording a
+/// `TransferFrom` event. Nothing is written when an error is returned.
+fn transfer_tokens_from(
+    spender: Key,
+    owner: Key,
+    recipient: Key,
+    amount: U256,
+) -> Result<(), Cep18Error> {
     if owner == recipient {
-        revert(Cep18Error::CannotTargetSelfUser);
+        return Err(Cep18Error::CannotTargetSelfUser);
     }
-    let amount: U256 = runtime::get_named_arg(AMOUNT);
     if amount.is_zero() {
-        return;
+        return Ok(());
     }
 
     let allowances_uref = get_allowances_uref();
     let spender_allowance: U256 = read_allowance_from(allowances_uref, owner, spender);
     let new_spender_allowance = spender_allowance
         .checked_sub(amount)
-        .ok_or(Cep18Error::InsufficientAllowance)
-        .unwrap_or_revert();
+        .ok_or(Cep18Error::InsufficientAllowance)?;
 
-    transfer_balance(owner, recipient, amount).unwrap_or_revert();
+    restrictions::check_transfer_restriction(owner, recipient, amount)?;
+    transfer_balance(owner, recipient, amount)?;
     write_allowance_to(allowances_uref, owner, spender, new_spender_allowance);
     events::record_event_dictionary(Event::TransferFrom(TransferFrom {
         spender,
         owner,
         recipient,
         amount,
-    }))
+    }));
+    Ok(())
 }
 
 #[no_mangle]
@@ -203,24 +331,37 @@ pub extern "C" fn mint() {
     let owner: Key = runtime::get_named_arg(OWNER);
     let amount: U256 = runtime::get_named_arg(AMOUNT);
 
-    let balances_uref = get_balances_uref();
+    if amount > read_from::<U256>(MINT_QUORUM_THRESHOLD) {
+        let mut action_args = owner.to_bytes().unwrap_or_revert();
+        action_args.append(&mut amount.to_bytes().unwrap_or_revert());
+        quorum::check_quorum(
+            get_immediate_caller_address().unwrap_or_revert(),
+            quorum::make_action_hash(MINT_ENTRY_POINT_NAME, &action_args),
+        )
+        .unwrap_or_revert();
+    }
+
+    mint_tokens(owner, amount);
+}
+
+/// Mints `amount` tokens to `owner` on behalf of the immediate caller, enforcing its minting
+/// allowance and rate limits.
+fn mint_tokens(owner: Key, amount: U256) {
+    balances::check_distributed().unwrap_or_revert();
+    let minter = get_immediate_caller_address().unwrap_or_revert();
+    if utils::get_sec_badge(minter) == Some(SecurityBadge::Minter) {
+        minters::spend_minter_allowance(minter, amount).unwrap_or_revert();
+    }
+    rate_limits::spend_mint_capacity(minter, amount).unwrap_or_revert();
+    restrictions::check_recipient_whitelisted(owner).unwrap_or_revert();
+
     let total_supply_uref = get_total_supply_uref();
-    let new_balance = {
-        let balance = read_balance_from(balances_uref, owner);
-        balance
-            .checked_add(amount)
-            .ok_or(Cep18Error::Overflow)
-            .unwrap_or_revert()
-    };
-    let new_total_supply = {
-        let total_supply: U256 = read_total_supply_from(total_supply_uref);
-        total_supply
-            .checked_add(amount)
-            .ok_or(Cep18Error::Overflow)
-            .unwrap_or_revert()
-    };
-    write_balance_to(balances_uref, owner, new_balance);
-    write_total_supply_to(total_supply_uref, new_total_supply);
+    let scaled = balances::credit_balance(get_balances_uref(), owner, amount).unwrap_or_revert();
+    let new_total_supply = read_scaled_total_supply_from(total_supply_uref)
+        .checked_add(scaled)
+        .ok_or(Cep18Error::Overflow)
+        .unwrap_or_revert();
+    write_scaled_total_supply_to(total_supply_uref, new_total_supply);
     events::record_event_dictionary(Event::Mint(Mint {
         recipient: owner,
         amount,
@@ -235,32 +376,40 @@ pub extern "C" fn burn() {
 
     let owner: Key = runtime::get_named_arg(OWNER);
 
-    if owner != get_immediate_caller_address().unwrap_or_revert() {
+    // Soulbound tokens can only leave their owner through burns, which Admins are trusted with.
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    if owner != caller
+        && !(restrictions::is_non_transferable()
+            && utils::get_sec_badge(caller) == Some(SecurityBadge::Admin))
+    {
         revert(Cep18Error::InvalidBurnTarget);
     }
 
     let amount: U256 = runtime::get_named_arg(AMOUNT);
-    let balances_uref = get_balances_uref();
-    let total_supply_uref = get_total_supply_uref();
-    let new_balance = {
-        let balance = read_balance_from(balances_uref, owner);
-        balance
-            .checked_sub(amount)
-            .ok_or(Cep18Error::InsufficientBalance)
-            .unwrap_or_revert()
-    };
-    let new_total_supply = {
-        let total_supply = read_total_supply_from(total_supply_uref);
-        total_supply
-            .checked_sub(amount)
-            .ok_or(Cep18Error::Overflow)
-            .unwrap_or_revert()
-    };
-    write_balance_to(balances_uref, owner, new_balance);
-    write_total_supply_to(total_supply_uref, new_total_supply);
+    if owner != caller {
+        let mut action_args = owner.to_bytes().unwrap_or_revert();
+        action_args.append(&mut amount.to_bytes().unwrap_or_revert());
+        quorum::check_action_quorum(BURN_ENTRY_POINT_NAME, &action_args).unwrap_or_revert();
+    }
+    abort_recovery(caller);
+    restrictions::check_sender_whitelisted(owner).unwrap_or_revert();
+
+    burn_tokens(owner, amount);
     events::record_event_dictionary(Event::Burn(Burn { owner, amount }))
 }
 
+/// Burns `amount` spendable tokens of `owner`.
+fn burn_tokens(owner: Key, amount: U256) {
+    balances::check_distributed().unwrap_or_revert();
+    let total_supply_uref = get_total_supply_uref();
+    let scaled = balances::debit_balance(get_balances_uref(), owner, amount).unwrap_or_revert();
+    let new_total_supply = read_scaled_total_supply_from(total_supply_uref)
+        .checked_sub(scaled)
+        .ok_or(Cep18Error::Overflow)
+        .unwrap_or_revert();
+    write_scaled_total_supply_to(total_supply_uref, new_total_supply);
+}
+
 /// Initiates the contracts states. Only used by the installer call,
 /// later calls will cause it to revert.
 #[no_mangle]
@@ -271,51 +420,115 @@ pub extern "C" fn init() {
     let package_hash = get_named_arg::<Key>(PACKAGE_HASH);
     put_key(PACKAGE_HASH, package_hash);
     storage::new_dictionary(ALLOWANCES).unwrap_or_revert();
+    storage::new_dictionary(ACTION_APPROVALS).unwrap_or_revert();
+    storage::new_dictionary(MINTER_ALLOWANCE).unwrap_or_revert();
+    storage::new_dictionary(MINTER_RATE_LIMITS).unwrap_or_revert();
+    storage::new_dictionary(MINTER_EPOCH_USAGE).unwrap_or_revert();
+    storage::new_dictionary(FROZEN).unwrap_or_revert();
+    storage::new_dictionary(WHITELIST).unwrap_or_revert();
+    storage::new_dictionary(LOCKS).unwrap_or_revert();
+    storage::new_dictionary(RECOVERIES).unwrap_or_revert();
+    storage::new_dictionary(HOLDS).unwrap_or_revert();
+    storage::new_dictionary(ACCOUNT_HOLDS).unwrap_or_revert();
+    storage::new_dictionary(OPERATORS).unwrap_or_revert();
+    storage::new_dictionary(CLAIMS).unwrap_or_revert();
+    storage::new_dictionary(RECIPIENT_CLAIMS).unwrap_or_revert();
+    storage::new_dictionary(STREAMS).unwrap_or_revert();
+    storage::new_dictionary(HTLCS).unwrap_or_revert();
+    storage::new_dictionary(EVENTS).unwrap_or_revert();
+    let transfer_allow_list_uref = storage::new_dictionary(TRANSFER_ALLOW_LIST).unwrap_or_revert();
     let balances_uref = storage::new_dictionary(BALANCES).unwrap_or_revert();
-    let initial_supply = runtime::get_named_arg(TOTAL_SUPPLY);
-    let caller = get_caller();
-    write_balance_to(balances_uref, caller.into(), initial_supply);
 
-    let security_badges_dict = storage::new_dictionary(SECURITY_BADGES).unwrap_or_revert();
-    dictionary_put(
-        security_badges_dict,
-        &base64::encode(Key::from(get_caller()).to_bytes().unwrap_or_revert()),
-        SecurityBadge::Admin,
-    );
+    // Schemas have to exist before the initial distribution is recorded.
+    init_events();
+    events::record_event_dictionary(Event::Init(Init {
+        name: read_from(NAME),
+        symbol: read_from(SYMBOL),
+        decimals: read_from(DECIMALS),
+        events_mode: read_from(EVENTS_MODE),
+        enable_mint_burn: read_from(ENABLE_MINT_BURN),
+        transfer_mode: read_from(TRANSFER_MODE),
+        transferability: read_from(TRANSFERABILITY),
+        enforcement_mode: read_from(ENFORCEMENT_MODE),
+        interest_mode: read_from(INTEREST_MODE),
+    }));
+
+    let initial_balances: Vec<(Key, U256)> = utils::get_optional_named_arg_with_user_errors(
+        INITIAL_BALANCES,
+        Cep18Error::InvalidInitialBalances,
+    )
+    .unwrap_or_else(|| vec![(get_caller().into(), runtime::get_named_arg(TOTAL_SUPPLY))]);
+    distribute_initial_balances(balances_uref, initial_balances);
+    let chunked_distribution: bool = utils::get_optional_named_arg_with_user_errors(
+        CHUNKED_DISTRIBUTION,
+        Cep18Error::InvalidInitialBalances,
+    )
+    .unwrap_or(false);
+    // Unless further chunks were announced, the initial balances must cover the total supply.
+    if !chunked_distribution && balances::check_distributed().is_err() {
+        revert(Cep18Error::InvalidInitialBalances);
+    }
+
+    storage::new_dictionary(SECURITY_BADGES).unwrap_or_revert();
+    storage::new_dictionary(ROLE_MEMBERS).unwrap_or_revert();
+    storage::new_dictionary(ROLE_MEMBER_INDICES).unwrap_or_revert();
 
     let admin_list: Option<Vec<Key>> =
         utils::get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep18Error::InvalidAdminList);
     let minter_list: Option<Vec<Key>> =
         utils::get_optional_named_arg_with_user_errors(MINTER_LIST, Cep18Error::InvalidMinterList);
+    let master_minter_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        MASTER_MINTER_LIST,
+        Cep18Error::InvalidMasterMinterList,
+    );
+    let kyc_operator_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        KYC_OPERATOR_LIST,
+        Cep18Error::InvalidKycOperatorList,
+    );
+    let enforcer_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        ENFORCER_LIST,
+        Cep18Error::InvalidEnforcerList,
+    );
+    let notary_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(NOTARY_LIST, Cep18Error::InvalidNotaryList);
 
-    init_events();
+    let transfer_allow_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        TRANSFER_ALLOW_LIST,
+        Cep18Error::InvalidTransferAllowList,
+    );
 
-    if let Some(minter_list) = minter_list {
-        for minter in minter_list {
-            dictionary_put(
-                security_badges_dict,
-                &base64::encode(minter.to_bytes().unwrap_or_revert()),
-                SecurityBadge::Minter,
-            );
+    if let Some(transfer_allow_list) = transfer_allow_list {
+        for account in transfer_allow_list {
+            restrictions::write_transfer_allowed_to(transfer_allow_list_uref, account, true);
         }
     }
-    if let Some(admin_list) = admin_list {
-        for admin in admin_list {
-            dictionary_put(
-                security_badges_dict,
-                &base64::encode(admin.to_bytes().unwrap_or_revert()),
-                SecurityBadge::Admin,
-            );
+
+    let mut badge_map: BTreeMap<Key, SecurityBadge> = BTreeMap::new();
+    badge_map.insert(get_caller().into(), SecurityBadge::Admin);
+    let badge_lists = [
+        (minter_list, SecurityBadge::Minter),
+        (master_minter_list, SecurityBadge::MasterMinter),
+        (kyc_operator_list, SecurityBadge::KycOperator),
+        (notary_list, SecurityBadge::Notary),
+        (enforcer_list, SecurityBadge::Enforcer),
+        (admin_list, SecurityBadge::Admin),
+    ];
+    for (list, badge) in badge_lists {
+        for account_key in list.unwrap_or_default() {
+            badge_map.insert(account_key, badge);
         }
     }
+    utils::change_sec_badge(&badge_map);
+    quorum::check_admin_count(read_from(ADMIN_QUORUM)).unwrap_or_revert();
 }
 
 /// Admin EntryPoint to manipulate the security access granted to users.
 /// One user can only possess one access group badge.
-/// Change strength: None > Admin > Minter
+/// Change strength: None > Admin > Enforcer > Notary > KycOperator > MasterMinter > Minter
 /// Change strength meaning by example: If user is added to both Minter and Admin they will be an
 /// Admin, also if a user is added to Admin and None then they will be removed from having rights.
-/// Beware: do not remove the last Admin because that will lock out all admin functionality.
+/// Changes leaving fewer Admins than the admin quorum are rejected.
+/// When an admin quorum is configured the change must first be approved through `approve_action`.
 #[no_mangle]
 pub extern "C" fn change_security() {
     if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
@@ -326,6 +539,20 @@ pub extern "C" fn change_security() {
         utils::get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep18Error::InvalidAdminList);
     let minter_list: Option<Vec<Key>> =
         utils::get_optional_named_arg_with_user_errors(MINTER_LIST, Cep18Error::InvalidMinterList);
+    let master_minter_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        MASTER_MINTER_LIST,
+        Cep18Error::InvalidMasterMinterList,
+    );
+    let kyc_operator_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        KYC_OPERATOR_LIST,
+        Cep18Error::InvalidKycOperatorList,
+    );
+    let enforcer_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        ENFORCER_LIST,
+        Cep18Error::InvalidEnforcerList,
+    );
+    let notary_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(NOTARY_LIST, Cep18Error::InvalidNotaryList);
     let none_list: Option<Vec<Key>> =
         utils::get_optional_named_arg_with_user_errors(NONE_LIST, Cep18Error::InvalidNoneList);
 
@@ -335,6 +562,26 @@ pub extern "C" fn change_security() {
             badge_map.insert(account_key, SecurityBadge::Minter);
         }
     }
+    if let Some(master_minter_list) = master_minter_list {
+        for account_key in master_minter_list {
+            badge_map.insert(account_key, SecurityBadge::MasterMinter);
+        }
+    }
+    if let Some(kyc_operator_list) = kyc_operator_list {
+        for account_key in kyc_operator_list {
+            badge_map.insert(account_key, SecurityBadge::KycOperator);
+        }
+    }
+    if let Some(notary_list) = notary_list {
+        for account_key in notary_list {
+            badge_map.insert(account_key, SecurityBadge::Notary);
+        }
+    }
+    if let Some(enforcer_list) = enforcer_list {
+        for account_key in enforcer_list {
+            badge_map.insert(account_key, SecurityBadge::Enforcer);
+        }
+    }
     if let Some(admin_list) = admin_list {
         for account_key in admin_list {
             badge_map.insert(account_key, SecurityBadge::Admin);
@@ -347,84 +594,1517 @@ pub extern "C" fn change_security() {
     }
 
     let caller = get_immediate_caller_address().unwrap_or_revert();
+    quorum::check_quorum(
+        caller,
+        quorum::make_action_hash(
+            CHANGE_SECURITY_ENTRY_POINT_NAME,
+            &badge_map.to_bytes().unwrap_or_revert(),
+        ),
+    )
+    .unwrap_or_revert();
     badge_map.remove(&caller);
 
     utils::change_sec_badge(&badge_map);
+    quorum::check_admin_count(read_from(ADMIN_QUORUM)).unwrap_or_revert();
     events::record_event_dictionary(Event::ChangeSecurity(ChangeSecurity {
         admin: get_immediate_caller_address().unwrap_or_revert(),
         sec_change_map: badge_map,
     }));
 }
 
+/// Admin EntryPoint to approve a privileged action identified by its action hash.
+/// The action runs once enough admins approved it, see `quorum::make_action_hash`.
 #[no_mangle]
-pub extern "C" fn migrate() {}
+pub extern "C" fn approve_action() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let approver = get_immediate_caller_address().unwrap_or_revert();
+    let action_hash: [u8; 32] = runtime::get_named_arg(ACTION_HASH);
+    let approvals = quorum::approve_action(approver, action_hash).unwrap_or_revert();
+    events::record_event_dictionary(Event::ApproveAction(ApproveAction {
+        approver,
+        action_hash,
+        approvals,
+    }));
+}
 
-pub fn install_contract() {
-    let name: String = runtime::get_named_arg(NAME);
-    let symbol: String = runtime::get_named_arg(SYMBOL);
-    let decimals: u8 = runtime::get_named_arg(DECIMALS);
-    let total_supply: U256 = runtime::get_named_arg(TOTAL_SUPPLY);
-    let events_mode: u8 =
-        utils::get_optional_named_arg_with_user_errors(EVENTS_MODE, Cep18Error::InvalidEventsMode)
-            .unwrap_or(0u8);
+/// Admin EntryPoint to change the number of admin approvals required by privileged actions, and
+/// the mint amount above which minting requires them.
+#[no_mangle]
+pub extern "C" fn set_quorum() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let admin_quorum: u8 = runtime::get_named_arg(ADMIN_QUORUM);
+    if admin_quorum == 0 {
+        revert(Cep18Error::InvalidQuorum);
+    }
+    quorum::check_admin_count(admin_quorum).unwrap_or_revert();
+    let mint_quorum_threshold: U256 = runtime::get_named_arg(MINT_QUORUM_THRESHOLD);
 
-    let admin_list: Option<Vec<Key>> =
-        utils::get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep18Error::InvalidAdminList);
-    let minter_list: Option<Vec<Key>> =
-        utils::get_optional_named_arg_with_user_errors(MINTER_LIST, Cep18Error::InvalidMinterList);
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    let mut action_args = admin_quorum.to_bytes().unwrap_or_revert();
+    action_args.append(&mut mint_quorum_threshold.to_bytes().unwrap_or_revert());
+    quorum::check_quorum(
+        caller,
+        quorum::make_action_hash(SET_QUORUM_ENTRY_POINT_NAME, &action_args),
+    )
+    .unwrap_or_revert();
 
-    let enable_mint_burn: u8 = utils::get_optional_named_arg_with_user_errors(
-        ENABLE_MINT_BURN,
-        Cep18Error::InvalidEnableMBFlag,
+    storage::write(utils::get_uref(ADMIN_QUORUM), admin_quorum);
+    storage::write(
+        utils::get_uref(MINT_QUORUM_THRESHOLD),
+        mint_quorum_threshold,
+    );
+    events::record_event_dictionary(Event::ChangeQuorum(ChangeQuorum {
+        admin: caller,
+        admin_quorum,
+        mint_quorum_threshold,
+    }));
+}
+
+/// Admin EntryPoint to change the events mode and whether minting and burning are enabled,
+/// registering the CES schemas when switching to CES.
+#[no_mangle]
+pub extern "C" fn set_modalities() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let events_mode: u8 = runtime::get_named_arg(EVENTS_MODE);
+    EventsMode::try_from(events_mode).unwrap_or_revert();
+    let enable_mint_burn: u8 = runtime::get_named_arg(ENABLE_MINT_BURN);
+    MintBurn::try_from(enable_mint_burn).unwrap_or_revert();
+    quorum::check_action_quorum(
+        SET_MODALITIES_ENTRY_POINT_NAME,
+        &[events_mode, enable_mint_burn],
     )
-    .unwrap_or(0);
+    .unwrap_or_revert();
 
-    let mut named_keys = NamedKeys::new();
-    named_keys.insert(NAME.to_string(), storage::new_uref(name.clone()).into());
-    named_keys.insert(SYMBOL.to_string(), storage::new_uref(symbol).into());
-    named_keys.insert(DECIMALS.to_string(), storage::new_uref(decimals).into());
-    named_keys.insert(
+    storage::write(utils::get_uref(EVENTS_MODE), events_mode);
+    storage::write(utils::get_uref(ENABLE_MINT_BURN), enable_mint_burn);
+    init_events();
+    events::record_event_dictionary(Event::ChangeModalities(ChangeModalities {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        events_mode,
+        enable_mint_burn,
+    }));
+}
+
+/// Admin EntryPoint to replace the description, icon URL, website and free-form metadata of the
+/// token.
+#[no_mangle]
+pub extern "C" fn set_metadata() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let description: String = runtime::get_named_arg(DESCRIPTION);
+    let icon_url: String = runtime::get_named_arg(ICON_URL);
+    let website: String = runtime::get_named_arg(WEBSITE);
+    let metadata: BTreeMap<String, String> = runtime::get_named_arg(METADATA);
+    let mut action_args = description.to_bytes().unwrap_or_revert();
+    action_args.append(&mut icon_url.to_bytes().unwrap_or_revert());
+    action_args.append(&mut website.to_bytes().unwrap_or_revert());
+    action_args.append(&mut metadata.to_bytes().unwrap_or_revert());
+    quorum::check_action_quorum(SET_METADATA_ENTRY_POINT_NAME, &action_args).unwrap_or_revert();
+
+    storage::write(utils::get_uref(DESCRIPTION), description.clone());
+    storage::write(utils::get_uref(ICON_URL), icon_url.clone());
+    storage::write(utils::get_uref(WEBSITE), website.clone());
+    storage::write(utils::get_uref(METADATA), metadata.clone());
+    events::record_event_dictionary(Event::MetadataUpdated(MetadataUpdated {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        description,
+        icon_url,
+        website,
+        metadata,
+    }));
+}
+
+/// Returns the token properties, metadata and modalities, stringified, along with the free-form
+/// metadata, sparing wallets one query per named key.
+#[no_mangle]
+pub extern "C" fn token_info() {
+    let mut token_info: BTreeMap<String, String> = BTreeMap::new();
+    for name in [NAME, SYMBOL, DESCRIPTION, ICON_URL, WEBSITE].iter() {
+        token_info.insert(name.to_string(), read_from::<String>(name));
+    }
+    token_info.insert(
         TOTAL_SUPPLY.to_string(),
-        storage::new_uref(total_supply).into(),
-    );
-    named_keys.insert(
-        EVENTS_MODE.to_string(),
-        storage::new_uref(events_mode).into(),
-    );
-    named_keys.insert(
-        ENABLE_MINT_BURN.to_string(),
-        storage::new_uref(enable_mint_burn).into(),
+        read_total_supply_from(get_total_supply_uref()).to_string(),
     );
-    let entry_points = generate_entry_points();
+    for name in [
+        DECIMALS,
+        EVENTS_MODE,
+        ENABLE_MINT_BURN,
+        TRANSFER_MODE,
+        TRANSFERABILITY,
+        ENFORCEMENT_MODE,
+        INTEREST_MODE,
+    ]
+    .iter()
+    {
+        token_info.insert(name.to_string(), read_from::<u8>(name).to_string());
+    }
+    let metadata: BTreeMap<String, String> = read_from(METADATA);
+    runtime::ret(CLValue::from_t((token_info, metadata)).unwrap_or_revert());
+}
 
-    let hash_key_name = format!("{HASH_KEY_NAME_PREFIX}{name}");
+/// Returns the ids of the interfaces supported by the contract, derived from its entry points and
+/// modalities.
+#[no_mangle]
+pub extern "C" fn supported_interfaces() {
+    runtime::ret(CLValue::from_t(interfaces::supported_interfaces()).unwrap_or_revert());
+}
 
-    let (contract_hash, contract_version) = storage::new_contract(
-        entry_points,
-        Some(named_keys),
-        Some(hash_key_name.clone()),
-        Some(format!("{ACCESS_KEY_NAME_PREFIX}{name}")),
-    );
-    let package_hash = runtime::get_key(&hash_key_name).unwrap_or_revert();
+/// Returns the version of the crate the contract was built from and the version of the layout of
+/// its storage.
+#[no_mangle]
+pub extern "C" fn contract_version() {
+    let crate_version = String::from(env!("CARGO_PKG_VERSION"));
+    let schema_version: u32 = read_from(SCHEMA_VERSION);
+    runtime::ret(CLValue::from_t((crate_version, schema_version)).unwrap_or_revert());
+}
 
-    // Store contract_hash and contract_version under the keys CONTRACT_NAME and CONTRACT_VERSION
-    runtime::put_key(
-        &format!("{CONTRACT_NAME_PREFIX}{name}"),
-        contract_hash.into(),
-    );
-    runtime::put_key(
-        &format!("{CONTRACT_VERSION_PREFIX}{name}"),
-        storage::new_uref(contract_version).into(),
-    );
-    // Call contract to initialize it
-    let mut init_args = runtime_args! {TOTAL_SUPPLY => total_supply, PACKAGE_HASH => package_hash};
+/// Returns the security badge held by a key, if any.
+#[no_mangle]
+pub extern "C" fn security_badge_of() {
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    runtime::ret(CLValue::from_t(utils::get_sec_badge(address)).unwrap_or_revert());
+}
 
-    if let Some(admin_list) = admin_list {
-        init_args.insert(ADMIN_LIST, admin_list).unwrap_or_revert();
+/// Returns up to `count` keys holding `badge`, skipping the first `start` ones.
+fn ret_role_members(badge: SecurityBadge) {
+    let start: u32 = runtime::get_named_arg(START);
+    let count: u32 = runtime::get_named_arg(COUNT);
+    let members: Vec<Key> = utils::read_role_members(badge, start, count);
+    runtime::ret(CLValue::from_t(members).unwrap_or_revert());
+}
+
+/// Returns a page of the Admins.
+#[no_mangle]
+pub extern "C" fn admins() {
+    ret_role_members(SecurityBadge::Admin);
+}
+
+/// Returns a page of the Minters.
+#[no_mangle]
+pub extern "C" fn minters() {
+    ret_role_members(SecurityBadge::Minter);
+}
+
+/// Returns up to `count` events of the native event log, starting from the sequence number
+/// `start`.
+#[no_mangle]
+pub extern "C" fn get_events() {
+    let start: u32 = runtime::get_named_arg(START);
+    let count: u32 = runtime::get_named_arg(COUNT);
+    let events: Vec<BTreeMap<String, String>> = (u64::from(start)..)
+        .take(count as usize)
+        .map_while(events::read_native_event)
+        .collect();
+    runtime::ret(CLValue::from_t(events).unwrap_or_revert());
+}
+
+/// MasterMinter EntryPoint to grant a minting allowance to a minter.
+/// Keys without a badge become Minters, other badges are left untouched.
+#[no_mangle]
+pub extern "C" fn configure_minter() {
+    sec_check(vec![SecurityBadge::MasterMinter]);
+    let minter: Key = runtime::get_named_arg(MINTER);
+    let allowance: U256 = runtime::get_named_arg(ALLOWANCE);
+
+    if matches!(
+        utils::get_sec_badge(minter),
+        None | Some(SecurityBadge::None)
+    ) {
+        let mut badge_map: BTreeMap<Key, SecurityBadge> = BTreeMap::new();
+        badge_map.insert(minter, SecurityBadge::Minter);
+        utils::change_sec_badge(&badge_map);
     }
-    if let Some(minter_list) = minter_list {
-        init_args
-            .insert(MINTER_LIST, minter_list)
+    minters::write_minter_allowance_to(minters::get_minter_allowance_uref(), minter, allowance);
+    events::record_event_dictionary(Event::MinterConfigured(MinterConfigured {
+        master_minter: get_immediate_caller_address().unwrap_or_revert(),
+        minter,
+        allowance,
+    }));
+}
+
+/// MasterMinter EntryPoint to revoke the Minter badge and minting allowance of a minter.
+#[no_mangle]
+pub extern "C" fn remove_minter() {
+    sec_check(vec![SecurityBadge::MasterMinter]);
+    let minter: Key = runtime::get_named_arg(MINTER);
+    if utils::get_sec_badge(minter) != Some(SecurityBadge::Minter) {
+        revert(Cep18Error::InvalidMinterList);
+    }
+
+    let mut badge_map: BTreeMap<Key, SecurityBadge> = BTreeMap::new();
+    badge_map.insert(minter, SecurityBadge::None);
+    utils::change_sec_badge(&badge_map);
+    // Dictionary items cannot be removed: a zero allowance keeps the minter from minting without
+    // limit should it be granted the Minter badge again, until a MasterMinter configures it.
+    minters::write_minter_allowance_to(minters::get_minter_allowance_uref(), minter, U256::zero());
+    events::record_event_dictionary(Event::MinterRemoved(MinterRemoved {
+        master_minter: get_immediate_caller_address().unwrap_or_revert(),
+        minter,
+    }));
+}
+
+/// Returns the amount a minter can still mint, `U256::MAX` for minters without a configured
+/// allowance and zero for keys which are not minters.
+#[no_mangle]
+pub extern "C" fn minter_allowance() {
+    let minter: Key = runtime::get_named_arg(MINTER);
+    let allowance = if utils::get_sec_badge(minter) == Some(SecurityBadge::Minter) {
+        minters::read_minter_allowance_from(minters::get_minter_allowance_uref(), minter)
+            .unwrap_or(U256::MAX)
+    } else {
+        U256::zero()
+    };
+    runtime::ret(CLValue::from_t(allowance).unwrap_or_revert());
+}
+
+/// Admin EntryPoint to limit the amount which can be minted per epoch of `epoch_duration`
+/// milliseconds. The limit applies to all mints, or to the mints of `minter` when the optional
+/// argument is provided. An `epoch_duration` of zero removes the limit.
+#[no_mangle]
+pub extern "C" fn set_mint_rate_limit() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let epoch_duration: u64 = runtime::get_named_arg(EPOCH_DURATION);
+    let epoch_limit: U256 = runtime::get_named_arg(EPOCH_LIMIT);
+    let minter: Option<Key> =
+        utils::get_optional_named_arg_with_user_errors(MINTER, Cep18Error::InvalidMinterList);
+    let mut action_args = epoch_duration.to_bytes().unwrap_or_revert();
+    action_args.append(&mut epoch_limit.to_bytes().unwrap_or_revert());
+    action_args.append(&mut minter.to_bytes().unwrap_or_revert());
+    quorum::check_action_quorum(SET_MINT_RATE_LIMIT_ENTRY_POINT_NAME, &action_args)
+        .unwrap_or_revert();
+
+    match minter {
+        Some(minter) => rate_limits::write_minter_rate_limit(minter, epoch_duration, epoch_limit),
+        None => rate_limits::write_global_rate_limit(epoch_duration, epoch_limit),
+    }
+}
+
+/// Returns the amount a minter can still mint during the current epochs.
+#[no_mangle]
+pub extern "C" fn remaining_mint_capacity() {
+    let minter: Key = runtime::get_named_arg(MINTER);
+    let capacity = rate_limits::remaining_mint_capacity(minter);
+    runtime::ret(CLValue::from_t(capacity).unwrap_or_revert());
+}
+
+/// Admin EntryPoint to freeze an account, preventing it from sending or receiving tokens.
+#[no_mangle]
+pub extern "C" fn freeze() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    quorum::check_action_quorum(
+        FREEZE_ENTRY_POINT_NAME,
+        &account.to_bytes().unwrap_or_revert(),
+    )
+    .unwrap_or_revert();
+    restrictions::write_frozen_to(restrictions::get_frozen_uref(), account, true);
+    events::record_event_dictionary(Event::Freeze(Freeze {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        account,
+    }));
+}
+
+/// Admin EntryPoint to unfreeze a frozen account.
+#[no_mangle]
+pub extern "C" fn unfreeze() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    quorum::check_action_quorum(
+        UNFREEZE_ENTRY_POINT_NAME,
+        &account.to_bytes().unwrap_or_revert(),
+    )
+    .unwrap_or_revert();
+    restrictions::write_frozen_to(restrictions::get_frozen_uref(), account, false);
+    events::record_event_dictionary(Event::Unfreeze(Unfreeze {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        account,
+    }));
+}
+
+/// Returns the restriction code preventing a transfer, `0` if the transfer is allowed.
+#[no_mangle]
+pub extern "C" fn detect_transfer_restriction() {
+    let from: Key = runtime::get_named_arg(FROM);
+    let to: Key = runtime::get_named_arg(TO);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let restriction = restrictions::detect_transfer_restriction(from, to, amount);
+    runtime::ret(CLValue::from_t(restriction as u8).unwrap_or_revert());
+}
+
+/// Returns the human readable message of a restriction code.
+#[no_mangle]
+pub extern "C" fn message_for_transfer_restriction() {
+    let restriction_code: u8 = runtime::get_named_arg(RESTRICTION_CODE);
+    let message = restrictions::TransferRestriction::message_for_code(restriction_code);
+    runtime::ret(CLValue::from_t(message.to_string()).unwrap_or_revert());
+}
+
+/// KycOperator EntryPoint to add accounts to the whitelist.
+#[no_mangle]
+pub extern "C" fn add_to_whitelist() {
+    sec_check(vec![SecurityBadge::KycOperator]);
+    let accounts: Vec<Key> = runtime::get_named_arg(ACCOUNTS);
+    let whitelist_uref = restrictions::get_whitelist_uref();
+    for account in &accounts {
+        restrictions::write_whitelisted_to(whitelist_uref, *account, true);
+    }
+    events::record_event_dictionary(Event::AddToWhitelist(AddToWhitelist {
+        operator: get_immediate_caller_address().unwrap_or_revert(),
+        accounts,
+    }));
+}
+
+/// KycOperator EntryPoint to remove accounts from the whitelist.
+#[no_mangle]
+pub extern "C" fn remove_from_whitelist() {
+    sec_check(vec![SecurityBadge::KycOperator]);
+    let accounts: Vec<Key> = runtime::get_named_arg(ACCOUNTS);
+    let whitelist_uref = restrictions::get_whitelist_uref();
+    for account in &accounts {
+        restrictions::write_whitelisted_to(whitelist_uref, *account, false);
+    }
+    events::record_event_dictionary(Event::RemoveFromWhitelist(RemoveFromWhitelist {
+        operator: get_immediate_caller_address().unwrap_or_revert(),
+        accounts,
+    }));
+}
+
+/// Returns whether an account is whitelisted.
+#[no_mangle]
+pub extern "C" fn is_whitelisted() {
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    let whitelisted =
+        restrictions::read_whitelisted_from(restrictions::get_whitelist_uref(), account);
+    runtime::ret(CLValue::from_t(whitelisted).unwrap_or_revert());
+}
+
+/// Mints tokens which are released linearly over `duration` milliseconds from `start`, nothing
+/// being released before `start + cliff`. `start` defaults to the current block time.
+/// The locked part of the balance cannot be transferred or burnt.
+#[no_mangle]
+pub extern "C" fn mint_locked() {
+    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
+        revert(Cep18Error::MintBurnDisabled);
+    }
+
+    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Minter]);
+
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let cliff: u64 = runtime::get_named_arg(CLIFF);
+    let duration: u64 = runtime::get_named_arg(DURATION);
+    let start: u64 =
+        utils::get_optional_named_arg_with_user_errors(START, Cep18Error::InvalidVestingSchedule)
+            .unwrap_or_else(|| u64::from(runtime::get_blocktime()));
+
+    if amount > read_from::<U256>(MINT_QUORUM_THRESHOLD) {
+        let mut action_args = owner.to_bytes().unwrap_or_revert();
+        action_args.append(&mut amount.to_bytes().unwrap_or_revert());
+        quorum::check_quorum(
+            get_immediate_caller_address().unwrap_or_revert(),
+            quorum::make_action_hash(MINT_LOCKED_ENTRY_POINT_NAME, &action_args),
+        )
+        .unwrap_or_revert();
+    }
+
+    mint_tokens(owner, amount);
+    vesting::add_lock(
+        owner,
+        vesting::Lock {
+            amount,
+            start,
+            cliff,
+            duration,
+        },
+    )
+    .unwrap_or_revert();
+    events::record_event_dictionary(Event::LockCreated(LockCreated {
+        owner,
+        amount,
+        start,
+        cliff,
+        duration,
+    }));
+}
+
+/// Returns the part of the balance of an account which is still locked.
+#[no_mangle]
+pub extern "C" fn locked_balance_of() {
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    let locked = vesting::locked_balance_of(address);
+    runtime::ret(CLValue::from_t(locked).unwrap_or_revert());
+}
+
+/// Returns the part of the balance of an account which can be moved.
+#[no_mangle]
+pub extern "C" fn releasable_of() {
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    let balance = read_balance_from(get_balances_uref(), address);
+    let releasable = balance.saturating_sub(vesting::locked_balance_of(address));
+    runtime::ret(CLValue::from_t(releasable).unwrap_or_revert());
+}
+
+/// Enforcer EntryPoint to move tokens between accounts without the approval of the owner, e.g.
+/// under court order. Disabled unless the `enforcement_mode` modality allows it.
+#[no_mangle]
+pub extern "C" fn force_transfer() {
+    sec_check(vec![SecurityBadge::Enforcer]);
+    let from: Key = runtime::get_named_arg(FROM);
+    let to: Key = runtime::get_named_arg(TO);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let reason: String = runtime::get_named_arg(REASON);
+
+    if from == to {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    utils::check_not_custody(from).unwrap_or_revert();
+    restrictions::check_enforcement_restriction(from, Some(to)).unwrap_or_revert();
+    transfer_balance(from, to, amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::ForcedTransfer(ForcedTransfer {
+        enforcer: get_immediate_caller_address().unwrap_or_revert(),
+        from,
+        to,
+        amount,
+        reason,
+    }));
+}
+
+/// Enforcer EntryPoint to burn tokens of an account without the approval of the owner.
+/// Disabled unless the `enforcement_mode` modality allows it.
+#[no_mangle]
+pub extern "C" fn clawback() {
+    sec_check(vec![SecurityBadge::Enforcer]);
+    let from: Key = runtime::get_named_arg(FROM);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let reason: String =
+        utils::get_optional_named_arg_with_user_errors(REASON, Cep18Error::InvalidReason)
+            .unwrap_or_default();
+
+    utils::check_not_custody(from).unwrap_or_revert();
+    restrictions::check_enforcement_restriction(from, None).unwrap_or_revert();
+    burn_tokens(from, amount);
+    events::record_event_dictionary(Event::Clawback(Clawback {
+        enforcer: get_immediate_caller_address().unwrap_or_revert(),
+        from,
+        amount,
+        reason,
+    }));
+}
+
+/// Admin EntryPoint to open the recovery of the balance of a lost key to `new_owner`.
+/// The recovery can be finalized once the `recovery_delay` elapsed, unless the lost key proves it
+/// is still in use by calling `cancel_recovery` or any entry point acting on its tokens.
+/// When an admin quorum is configured the recovery must first be approved through
+/// `approve_action`.
+#[no_mangle]
+pub extern "C" fn initiate_recovery() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let lost: Key = runtime::get_named_arg(LOST);
+    let new_owner: Key = runtime::get_named_arg(NEW_OWNER);
+    if lost == new_owner {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    utils::check_not_custody(lost).unwrap_or_revert();
+
+    let mut action_args = lost.to_bytes().unwrap_or_revert();
+    action_args.append(&mut new_owner.to_bytes().unwrap_or_revert());
+    quorum::check_quorum(
+        get_immediate_caller_address().unwrap_or_revert(),
+        quorum::make_action_hash(INITIATE_RECOVERY_ENTRY_POINT_NAME, &action_args),
+    )
+    .unwrap_or_revert();
+
+    let finalize_after = recovery::initiate_recovery(lost, new_owner).unwrap_or_revert();
+    events::record_event_dictionary(Event::RecoveryInitiated(RecoveryInitiated {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        lost,
+        new_owner,
+        finalize_after,
+    }));
+}
+
+/// EntryPoint to abort the pending recovery of a key, callable by the key itself or an Admin.
+#[no_mangle]
+pub extern "C" fn cancel_recovery() {
+    let lost: Key = runtime::get_named_arg(LOST);
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    if caller != lost {
+        sec_check(vec![SecurityBadge::Admin]);
+        quorum::check_action_quorum(
+            CANCEL_RECOVERY_ENTRY_POINT_NAME,
+            &lost.to_bytes().unwrap_or_revert(),
+        )
+        .unwrap_or_revert();
+    }
+
+    if !recovery::cancel_recovery(lost) {
+        revert(Cep18Error::NoPendingRecovery);
+    }
+    events::record_event_dictionary(Event::RecoveryCancelled(RecoveryCancelled {
+        canceller: caller,
+        lost,
+    }));
+}
+
+/// Admin EntryPoint to move the whole balance of a lost key, along with its vesting locks, to the
+/// new owner once the challenge window of the recovery is over.
+#[no_mangle]
+pub extern "C" fn finalize_recovery() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let lost: Key = runtime::get_named_arg(LOST);
+    quorum::check_action_quorum(
+        FINALIZE_RECOVERY_ENTRY_POINT_NAME,
+        &lost.to_bytes().unwrap_or_revert(),
+    )
+    .unwrap_or_revert();
+    let new_owner = recovery::finalize_recovery(lost).unwrap_or_revert();
+    restrictions::check_recipient_whitelisted(new_owner).unwrap_or_revert();
+    let admin = get_immediate_caller_address().unwrap_or_revert();
+
+    // Holds are settled against the lost key by their notaries, so they are released rather than
+    // migrated to the new owner.
+    for operation_id in holds::release_account_holds(lost) {
+        events::record_event_dictionary(Event::HoldReleased(HoldReleased {
+            releaser: admin,
+            operation_id,
+        }));
+    }
+    let amount = read_balance_from(get_balances_uref(), lost);
+    vesting::move_locks(lost, new_owner);
+    transfer_balance(lost, new_owner, amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::Transfer(Transfer {
+        sender: lost,
+        recipient: new_owner,
+        amount,
+    }));
+    events::record_event_dictionary(Event::RecoveryFinalized(RecoveryFinalized {
+        admin,
+        lost,
+        new_owner,
+        amount,
+    }));
+}
+
+/// Returns the pending recovery of a key as a `(new_owner, finalize_after)` pair, if any.
+#[no_mangle]
+pub extern "C" fn pending_recovery() {
+    let lost: Key = runtime::get_named_arg(LOST);
+    let recovery = recovery::read_recovery_from(recovery::get_recoveries_uref(), lost);
+    runtime::ret(CLValue::from_t(recovery).unwrap_or_revert());
+}
+
+/// Notary EntryPoint to hold `amount` of the balance of `from` for a transfer to `to`, until the
+/// hold is executed, released or reaches its `expiration` block time.
+#[no_mangle]
+pub extern "C" fn hold() {
+    sec_check(vec![SecurityBadge::Notary]);
+    let operation_id: String = runtime::get_named_arg(OPERATION_ID);
+    let from: Key = runtime::get_named_arg(FROM);
+    let to: Key = runtime::get_named_arg(TO);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let expiration: u64 = runtime::get_named_arg(EXPIRATION);
+    if from == to {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    utils::check_not_custody(from).unwrap_or_revert();
+
+    let notary = get_immediate_caller_address().unwrap_or_revert();
+    holds::create_hold(
+        &operation_id,
+        holds::Hold {
+            notary,
+            from,
+            to,
+            amount,
+            expiration,
+            status: holds::HoldStatus::Ordered,
+        },
+    )
+    .unwrap_or_revert();
+    events::record_event_dictionary(Event::HoldCreated(HoldCreated {
+        notary,
+        operation_id,
+        from,
+        to,
+        amount,
+        expiration,
+    }));
+}
+
+/// EntryPoint to move the held amount to the recipient of the hold. Restricted to the notary
+/// which ordered the hold.
+#[no_mangle]
+pub extern "C" fn execute_hold() {
+    let operation_id: String = runtime::get_named_arg(OPERATION_ID);
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    let hold = holds::read_hold_from(holds::get_holds_uref(), &operation_id)
+        .unwrap_or_revert_with(Cep18Error::HoldNotFound);
+    if hold.notary != caller {
+        revert(Cep18Error::InsufficientRights);
+    }
+
+    holds::close_hold(&operation_id, holds::HoldStatus::Executed).unwrap_or_revert();
+    restrictions::check_transfer_restriction(hold.from, hold.to, hold.amount).unwrap_or_revert();
+    transfer_balance(hold.from, hold.to, hold.amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::Transfer(Transfer {
+        sender: hold.from,
+        recipient: hold.to,
+        amount: hold.amount,
+    }));
+    events::record_event_dictionary(Event::HoldExecuted(HoldExecuted {
+        notary: caller,
+        operation_id,
+    }));
+}
+
+/// EntryPoint to return the held amount to the spendable balance. Restricted to the notary which
+/// ordered the hold, or to the holder once the hold expired.
+#[no_mangle]
+pub extern "C" fn release_hold() {
+    let operation_id: String = runtime::get_named_arg(OPERATION_ID);
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    let hold = holds::read_hold_from(holds::get_holds_uref(), &operation_id)
+        .unwrap_or_revert_with(Cep18Error::HoldNotFound);
+    let expired = hold.status_at(u64::from(runtime::get_blocktime())) == holds::HoldStatus::Expired;
+    if hold.notary != caller && !(expired && hold.from == caller) {
+        revert(Cep18Error::InsufficientRights);
+    }
+
+    holds::close_hold(&operation_id, holds::HoldStatus::Released).unwrap_or_revert();
+    events::record_event_dictionary(Event::HoldReleased(HoldReleased {
+        releaser: caller,
+        operation_id,
+    }));
+}
+
+/// Returns the amount of the balance of an account held by unexpired holds.
+#[no_mangle]
+pub extern "C" fn balance_on_hold() {
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    let held = holds::balance_on_hold(account);
+    runtime::ret(CLValue::from_t(held).unwrap_or_revert());
+}
+
+/// Returns the status code of the hold of an operation, see `holds::HoldStatus`.
+#[no_mangle]
+pub extern "C" fn hold_status() {
+    let operation_id: String = runtime::get_named_arg(OPERATION_ID);
+    let status = holds::read_hold_from(holds::get_holds_uref(), &operation_id)
+        .map_or(holds::HoldStatus::Nonexistent, |hold| {
+            hold.status_at(u64::from(runtime::get_blocktime()))
+        });
+    runtime::ret(CLValue::from_t(status as u8).unwrap_or_revert());
+}
+
+/// EntryPoint to authorize an operator to move any amount of the tokens of the caller.
+#[no_mangle]
+pub extern "C" fn authorize_operator() {
+    let holder = get_immediate_caller_address().unwrap_or_revert();
+    let operator: Key = runtime::get_named_arg(OPERATOR);
+    if operator == holder {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    abort_recovery(holder);
+    restrictions::check_delegation_allowed(operator).unwrap_or_revert();
+
+    operators::write_operator_to(operators::get_operators_uref(), holder, operator, true);
+    events::record_event_dictionary(Event::AuthorizedOperator(AuthorizedOperator {
+        operator,
+        holder,
+    }));
+}
+
+/// EntryPoint to revoke an operator of the caller, including default operators.
+#[no_mangle]
+pub extern "C" fn revoke_operator() {
+    let holder = get_immediate_caller_address().unwrap_or_revert();
+    let operator: Key = runtime::get_named_arg(OPERATOR);
+    if operator == holder {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    abort_recovery(holder);
+
+    operators::write_operator_to(operators::get_operators_uref(), holder, operator, false);
+    events::record_event_dictionary(Event::RevokedOperator(RevokedOperator { operator, holder }));
+}
+
+/// Returns whether an operator can move the tokens of a holder.
+#[no_mangle]
+pub extern "C" fn is_operator_for() {
+    let operator: Key = runtime::get_named_arg(OPERATOR);
+    let holder: Key = runtime::get_named_arg(HOLDER);
+    let is_operator = operators::is_operator_for(operators::get_operators_uref(), operator, holder);
+    runtime::ret(CLValue::from_t(is_operator).unwrap_or_revert());
+}
+
+/// EntryPoint for operators to move tokens of a holder without allowance accounting.
+#[no_mangle]
+pub extern "C" fn operator_send() {
+    let operator = get_immediate_caller_address().unwrap_or_revert();
+    let holder: Key = runtime::get_named_arg(HOLDER);
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    if holder == recipient {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    utils::check_not_custody(holder).unwrap_or_revert();
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let data: Bytes = runtime::get_named_arg(DATA);
+    if !operators::is_operator_for(operators::get_operators_uref(), operator, holder) {
+        revert(Cep18Error::NotOperatorForHolder);
+    }
+    abort_recovery(operator);
+
+    restrictions::check_transfer_restriction(holder, recipient, amount).unwrap_or_revert();
+    transfer_balance(holder, recipient, amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::Sent(Sent {
+        operator,
+        holder,
+        recipient,
+        amount,
+        data,
+    }));
+}
+
+/// Admin EntryPoint to exempt an account, such as a redemption contract, from the
+/// non-transferability of soulbound tokens.
+#[no_mangle]
+pub extern "C" fn set_transfer_allowed() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    let allowed: bool = runtime::get_named_arg(ALLOWED);
+    let mut action_args = account.to_bytes().unwrap_or_revert();
+    action_args.append(&mut allowed.to_bytes().unwrap_or_revert());
+    quorum::check_action_quorum(SET_TRANSFER_ALLOWED_ENTRY_POINT_NAME, &action_args)
+        .unwrap_or_revert();
+    restrictions::write_transfer_allowed_to(
+        restrictions::get_transfer_allow_list_uref(),
+        account,
+        allowed,
+    );
+    events::record_event_dictionary(Event::ChangeTransferAllowList(ChangeTransferAllowList {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        account,
+        allowed,
+    }));
+}
+
+/// Admin EntryPoint to change the per-second interest rate, accruing the index at the previous
+/// rate first.
+#[no_mangle]
+pub extern "C" fn set_rate() {
+    sec_check(vec![SecurityBadge::Admin]);
+    if !interest::is_interest_bearing() {
+        revert(Cep18Error::InterestDisabled);
+    }
+    let rate: U256 = runtime::get_named_arg(RATE);
+    quorum::check_action_quorum(
+        SET_RATE_ENTRY_POINT_NAME,
+        &rate.to_bytes().unwrap_or_revert(),
+    )
+    .unwrap_or_revert();
+    interest::accrue();
+    storage::write(utils::get_uref(INTEREST_RATE), rate);
+    events::record_event_dictionary(Event::IndexUpdated(IndexUpdated {
+        index: read_from(ACCRUAL_INDEX),
+        rate,
+        timestamp: read_from(LAST_ACCRUAL),
+    }));
+}
+
+/// EntryPoint to escrow `amount` in the contract for `recipient`, who can claim it while the
+/// caller can reclaim it from the `expiry` block time.
+#[no_mangle]
+pub extern "C" fn send_claimable() {
+    let sender = get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    if sender == recipient {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let expiry: u64 = runtime::get_named_arg(EXPIRY);
+    abort_recovery(sender);
+
+    restrictions::check_transfer_restriction(sender, recipient, amount).unwrap_or_revert();
+    let scaled_amount = balances::escrow_balance(sender, amount).unwrap_or_revert();
+    let transfer_id = claims::create_claim(claims::Claim {
+        sender,
+        recipient,
+        amount,
+        scaled_amount,
+        expiry,
+    })
+    .unwrap_or_revert();
+    events::record_event_dictionary(Event::Transfer(Transfer {
+        sender,
+        recipient: utils::get_package_key(),
+        amount,
+    }));
+    events::record_event_dictionary(Event::ClaimableSent(ClaimableSent {
+        transfer_id,
+        sender,
+        recipient,
+        amount,
+        expiry,
+    }));
+    runtime::ret(CLValue::from_t(transfer_id).unwrap_or_revert());
+}
+
+/// EntryPoint to receive the amount escrowed by a claimable transfer. Restricted to its recipient.
+#[no_mangle]
+pub extern "C" fn claim() {
+    let transfer_id: u64 = runtime::get_named_arg(TRANSFER_ID);
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    let claim = claims::close_claim(transfer_id).unwrap_or_revert();
+    if claim.recipient != caller {
+        revert(Cep18Error::InsufficientRights);
+    }
+    abort_recovery(caller);
+
+    restrictions::check_release_restriction(claim.sender, claim.recipient).unwrap_or_revert();
+    let amount = balances::release_balance(claim.recipient, claim.scaled_amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::Transfer(Transfer {
+        sender: utils::get_package_key(),
+        recipient: claim.recipient,
+        amount,
+    }));
+    events::record_event_dictionary(Event::Claimed(Claimed {
+        transfer_id,
+        recipient: claim.recipient,
+        amount,
+    }));
+}
+
+/// EntryPoint to take back the amount escrowed by an unclaimed transfer. Restricted to its sender
+/// once expired.
+#[no_mangle]
+pub extern "C" fn reclaim() {
+    let transfer_id: u64 = runtime::get_named_arg(TRANSFER_ID);
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    let claim = claims::close_claim(transfer_id).unwrap_or_revert();
+    if claim.sender != caller {
+        revert(Cep18Error::InsufficientRights);
+    }
+    if u64::from(runtime::get_blocktime()) < claim.expiry {
+        revert(Cep18Error::ClaimNotExpired);
+    }
+    abort_recovery(caller);
+
+    restrictions::check_release_restriction(claim.sender, claim.sender).unwrap_or_revert();
+    let amount = balances::release_balance(claim.sender, claim.scaled_amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::Transfer(Transfer {
+        sender: utils::get_package_key(),
+        recipient: claim.sender,
+        amount,
+    }));
+    events::record_event_dictionary(Event::Reclaimed(Reclaimed {
+        transfer_id,
+        sender: claim.sender,
+        amount,
+    }));
+}
+
+/// Returns up to `count` transfer ids of the pending claims of `recipient`, skipping the first
+/// `start` ones.
+#[no_mangle]
+pub extern "C" fn pending_claims() {
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let start: u32 = runtime::get_named_arg(START);
+    let count: u32 = runtime::get_named_arg(COUNT);
+    let transfer_ids: Vec<u64> = claims::read_pending_claims(recipient)
+        .into_iter()
+        .skip(start as usize)
+        .take(count as usize)
+        .collect();
+    runtime::ret(CLValue::from_t(transfer_ids).unwrap_or_revert());
+}
+
+/// Returns the `(sender, amount, expiry)` of the pending claim of a transfer id.
+#[no_mangle]
+pub extern "C" fn pending_claim() {
+    let transfer_id: u64 = runtime::get_named_arg(TRANSFER_ID);
+    let claim = claims::read_claim_from(claims::get_claims_uref(), transfer_id)
+        .map(|claim| (claim.sender, claim.amount, claim.expiry));
+    runtime::ret(CLValue::from_t(claim).unwrap_or_revert());
+}
+
+/// EntryPoint to stream a `deposit` of the caller to `recipient` linearly between the `start` and
+/// `stop` block times, the deposit being held by the contract meanwhile.
+#[no_mangle]
+pub extern "C" fn create_stream() {
+    let sender = get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    if sender == recipient {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    let deposit: U256 = runtime::get_named_arg(DEPOSIT);
+    let start: u64 = runtime::get_named_arg(START);
+    let stop: u64 = runtime::get_named_arg(STOP);
+    abort_recovery(sender);
+
+    restrictions::check_transfer_restriction(sender, recipient, deposit).unwrap_or_revert();
+    let scaled_deposit = balances::escrow_balance(sender, deposit).unwrap_or_revert();
+    let stream_id = streams::create_stream(streams::Stream {
+        sender,
+        recipient,
+        deposit,
+        scaled_deposit,
+        start,
+        stop,
+        withdrawn: U256::zero(),
+    })
+    .unwrap_or_revert();
+    events::record_event_dictionary(Event::Transfer(Transfer {
+        sender,
+        recipient: utils::get_package_key(),
+        amount: deposit,
+    }));
+    events::record_event_dictionary(Event::StreamCreated(StreamCreated {
+        stream_id,
+        sender,
+        recipient,
+        deposit,
+        start,
+        stop,
+    }));
+    runtime::ret(CLValue::from_t(stream_id).unwrap_or_revert());
+}
+
+/// EntryPoint to receive `amount` of the streamed part of a deposit. Restricted to the recipient
+/// of the stream.
+#[no_mangle]
+pub extern "C" fn withdraw_from_stream() {
+    let stream_id: u64 = runtime::get_named_arg(STREAM_ID);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    let streams_uref = streams::get_streams_uref();
+    let mut stream = streams::read_stream_from(streams_uref, stream_id)
+        .unwrap_or_revert_with(Cep18Error::StreamNotFound);
+    if stream.recipient != caller {
+        revert(Cep18Error::InsufficientRights);
+    }
+    if amount > stream.withdrawable_at(u64::from(runtime::get_blocktime())) {
+        revert(Cep18Error::AmountNotStreamed);
+    }
+    abort_recovery(caller);
+
+    restrictions::check_release_restriction(stream.sender, stream.recipient).unwrap_or_revert();
+    let scaled_amount =
+        stream.scaled_part(stream.withdrawn + amount) - stream.scaled_part(stream.withdrawn);
+    stream.withdrawn += amount;
+    // Fully withdrawn streams are closed.
+    let open_stream = if stream.withdrawn == stream.deposit {
+        None
+    } else {
+        Some(stream)
+    };
+    streams::write_stream_to(streams_uref, stream_id, open_stream);
+    let released = balances::release_balance(stream.recipient, scaled_amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::Transfer(Transfer {
+        sender: utils::get_package_key(),
+        recipient: stream.recipient,
+        amount: released,
+    }));
+    events::record_event_dictionary(Event::StreamWithdrawn(StreamWithdrawn {
+        stream_id,
+        recipient: stream.recipient,
+        amount,
+    }));
+}
+
+/// EntryPoint to close a stream, paying its recipient the streamed part of the deposit not yet
+/// withdrawn and returning the rest to its sender. Restricted to the sender and the recipient of
+/// the stream.
+#[no_mangle]
+pub extern "C" fn cancel_stream() {
+    let stream_id: u64 = runtime::get_named_arg(STREAM_ID);
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    let streams_uref = streams::get_streams_uref();
+    let stream = streams::read_stream_from(streams_uref, stream_id)
+        .unwrap_or_revert_with(Cep18Error::StreamNotFound);
+    if stream.sender != caller && stream.recipient != caller {
+        revert(Cep18Error::InsufficientRights);
+    }
+    abort_recovery(caller);
+
+    let streamed = stream.streamed_at(u64::from(runtime::get_blocktime()));
+    let recipient_amount = streamed - stream.withdrawn;
+    let sender_amount = stream.deposit - streamed;
+    let scaled_streamed = stream.scaled_part(streamed);
+    streams::write_stream_to(streams_uref, stream_id, None);
+    for (recipient, amount, scaled_amount) in [
+        (
+            stream.recipient,
+            recipient_amount,
+            scaled_streamed - stream.scaled_part(stream.withdrawn),
+        ),
+        (
+            stream.sender,
+            sender_amount,
+            stream.scaled_deposit - scaled_streamed,
+        ),
+    ] {
+        if amount.is_zero() {
+            continue;
+        }
+        restrictions::check_release_restriction(stream.sender, recipient).unwrap_or_revert();
+        let released = balances::release_balance(recipient, scaled_amount).unwrap_or_revert();
+        events::record_event_dictionary(Event::Transfer(Transfer {
+            sender: utils::get_package_key(),
+            recipient,
+            amount: released,
+        }));
+    }
+    events::record_event_dictionary(Event::StreamCancelled(StreamCancelled {
+        stream_id,
+        canceller: caller,
+        sender_amount,
+        recipient_amount,
+    }));
+}
+
+/// Returns the part of the deposit of a stream its recipient can withdraw.
+#[no_mangle]
+pub extern "C" fn withdrawable_from_stream() {
+    let stream_id: u64 = runtime::get_named_arg(STREAM_ID);
+    let withdrawable = streams::read_stream_from(streams::get_streams_uref(), stream_id)
+        .map_or(U256::zero(), |stream| {
+            stream.withdrawable_at(u64::from(runtime::get_blocktime()))
+        });
+    runtime::ret(CLValue::from_t(withdrawable).unwrap_or_revert());
+}
+
+/// EntryPoint to escrow `amount` in the contract for `recipient` until the preimage of
+/// `hashlock` is revealed, the caller being able to take it back from the `timelock` block time.
+#[no_mangle]
+pub extern "C" fn htlc_lock() {
+    let sender = get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    if sender == recipient {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let hashlock: [u8; 32] = runtime::get_named_arg(HASHLOCK);
+    let timelock: u64 = runtime::get_named_arg(TIMELOCK);
+    abort_recovery(sender);
+
+    restrictions::check_transfer_restriction(sender, recipient, amount).unwrap_or_revert();
+    let scaled_amount = balances::escrow_balance(sender, amount).unwrap_or_revert();
+    let lock_id = htlc::create_htlc(htlc::Htlc {
+        sender,
+        recipient,
+        amount,
+        scaled_amount,
+        hashlock,
+        timelock,
+    })
+    .unwrap_or_revert();
+    events::record_event_dictionary(Event::Transfer(Transfer {
+        sender,
+        recipient: utils::get_package_key(),
+        amount,
+    }));
+    events::record_event_dictionary(Event::HtlcLocked(HtlcLocked {
+        lock_id,
+        sender,
+        recipient,
+        amount,
+        hashlock,
+        timelock,
+    }));
+    runtime::ret(CLValue::from_t(lock_id).unwrap_or_revert());
+}
+
+/// EntryPoint to release the amount escrowed by an HTLC to its recipient, revealing the preimage
+/// of its hashlock before its timelock.
+#[no_mangle]
+pub extern "C" fn htlc_redeem() {
+    let lock_id: u64 = runtime::get_named_arg(LOCK_ID);
+    let preimage: Bytes = runtime::get_named_arg(PREIMAGE);
+    let htlc = htlc::redeem_htlc(lock_id, &preimage).unwrap_or_revert();
+    restrictions::check_release_restriction(htlc.sender, htlc.recipient).unwrap_or_revert();
+
+    let amount = balances::release_balance(htlc.recipient, htlc.scaled_amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::Transfer(Transfer {
+        sender: utils::get_package_key(),
+        recipient: htlc.recipient,
+        amount,
+    }));
+    events::record_event_dictionary(Event::HtlcRedeemed(HtlcRedeemed {
+        lock_id,
+        recipient: htlc.recipient,
+        amount,
+        preimage,
+    }));
+}
+
+/// EntryPoint to take back the amount escrowed by an HTLC once its timelock expired. Restricted
+/// to its sender.
+#[no_mangle]
+pub extern "C" fn htlc_refund() {
+    let lock_id: u64 = runtime::get_named_arg(LOCK_ID);
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    let htlc = htlc::refund_htlc(lock_id).unwrap_or_revert();
+    if htlc.sender != caller {
+        revert(Cep18Error::InsufficientRights);
+    }
+    abort_recovery(caller);
+    restrictions::check_release_restriction(htlc.sender, htlc.sender).unwrap_or_revert();
+
+    let amount = balances::release_balance(htlc.sender, htlc.scaled_amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::Transfer(Transfer {
+        sender: utils::get_package_key(),
+        recipient: htlc.sender,
+        amount,
+    }));
+    events::record_event_dictionary(Event::HtlcRefunded(HtlcRefunded {
+        lock_id,
+        sender: htlc.sender,
+        amount,
+    }));
+}
+
+/// Admin EntryPoint to credit a further chunk of the initial balances, for distributions too
+/// large for the installation deploy. Recipients must be whitelisted when the whitelist is
+/// enforced. The token is live once the whole total supply is distributed.
+#[no_mangle]
+pub extern "C" fn init_distribute() {
+    sec_check(vec![SecurityBadge::Admin]);
+    if balances::check_distributed().is_ok() {
+        revert(Cep18Error::AlreadyInitialized);
+    }
+    let initial_balances: Vec<(Key, U256)> = runtime::get_named_arg(INITIAL_BALANCES);
+    quorum::check_action_quorum(
+        INIT_DISTRIBUTE_ENTRY_POINT_NAME,
+        &initial_balances.to_bytes().unwrap_or_revert(),
+    )
+    .unwrap_or_revert();
+    for (recipient, _) in &initial_balances {
+        restrictions::check_recipient_whitelisted(*recipient).unwrap_or_revert();
+    }
+    distribute_initial_balances(get_balances_uref(), initial_balances);
+}
+
+/// Credits initial balances out of the undistributed supply, recording a `Mint` event for each.
+/// The zero keys and the package hash holding the balances in custody cannot be credited.
+fn distribute_initial_balances(balances_uref: URef, initial_balances: Vec<(Key, U256)>) {
+    let undistributed_supply_uref = utils::get_uref(UNDISTRIBUTED_SUPPLY);
+    let mut undistributed_supply: U256 = storage::read(undistributed_supply_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    for (recipient, amount) in initial_balances {
+        if recipient == Key::Account(AccountHash::default()) || recipient == Key::Hash([0u8; 32]) {
+            revert(Cep18Error::InvalidInitialBalances);
+        }
+        utils::check_not_custody(recipient).unwrap_or_revert();
+        undistributed_supply = undistributed_supply
+            .checked_sub(amount)
+            .ok_or(Cep18Error::InvalidInitialBalances)
+            .unwrap_or_revert();
+        balances::credit_balance(balances_uref, recipient, amount).unwrap_or_revert();
+        if !amount.is_zero() {
+            events::record_event_dictionary(Event::Mint(Mint { recipient, amount }));
+        }
+    }
+    storage::write(undistributed_supply_uref, undistributed_supply);
+}
+
+/// Aborts the pending recovery of `account`, any transaction from the key proving it is not lost.
+fn abort_recovery(account: Key) {
+    if recovery::cancel_recovery(account) {
+        events::record_event_dictionary(Event::RecoveryCancelled(RecoveryCancelled {
+            canceller: account,
+            lost: account,
+        }));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn migrate() {}
+
+pub fn install_contract() {
+    let name: String = runtime::get_named_arg(NAME);
+    let symbol: String = runtime::get_named_arg(SYMBOL);
+    let decimals: u8 = runtime::get_named_arg(DECIMALS);
+    let total_supply: U256 = runtime::get_named_arg(TOTAL_SUPPLY);
+    utils::validate_metadata(&name, &symbol, decimals).unwrap_or_revert();
+    let events_mode: u8 =
+        utils::get_optional_named_arg_with_user_errors(EVENTS_MODE, Cep18Error::InvalidEventsMode)
+            .unwrap_or(0u8);
+
+    let admin_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep18Error::InvalidAdminList);
+    let minter_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(MINTER_LIST, Cep18Error::InvalidMinterList);
+    let master_minter_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        MASTER_MINTER_LIST,
+        Cep18Error::InvalidMasterMinterList,
+    );
+    let kyc_operator_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        KYC_OPERATOR_LIST,
+        Cep18Error::InvalidKycOperatorList,
+    );
+    let enforcer_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        ENFORCER_LIST,
+        Cep18Error::InvalidEnforcerList,
+    );
+    let notary_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(NOTARY_LIST, Cep18Error::InvalidNotaryList);
+    utils::validate_key_lists(&[
+        &admin_list,
+        &minter_list,
+        &master_minter_list,
+        &kyc_operator_list,
+        &enforcer_list,
+        &notary_list,
+    ])
+    .unwrap_or_revert();
+
+    let enable_mint_burn: u8 = utils::get_optional_named_arg_with_user_errors(
+        ENABLE_MINT_BURN,
+        Cep18Error::InvalidEnableMBFlag,
+    )
+    .unwrap_or(0);
+    let transfer_mode: u8 = utils::get_optional_named_arg_with_user_errors(
+        TRANSFER_MODE,
+        Cep18Error::InvalidTransferMode,
+    )
+    .unwrap_or(0);
+    TransferMode::try_from(transfer_mode).unwrap_or_revert();
+    let enforcement_mode: u8 = utils::get_optional_named_arg_with_user_errors(
+        ENFORCEMENT_MODE,
+        Cep18Error::InvalidEnforcementMode,
+    )
+    .unwrap_or(0);
+    EnforcementMode::try_from(enforcement_mode).unwrap_or_revert();
+    let transferability: u8 = utils::get_optional_named_arg_with_user_errors(
+        TRANSFERABILITY,
+        Cep18Error::InvalidTransferability,
+    )
+    .unwrap_or(0);
+    Transferability::try_from(transferability).unwrap_or_revert();
+    let interest_mode: u8 = utils::get_optional_named_arg_with_user_errors(
+        INTEREST_MODE,
+        Cep18Error::InvalidInterestMode,
+    )
+    .unwrap_or(0);
+    InterestMode::try_from(interest_mode).unwrap_or_revert();
+    let interest_rate: U256 = utils::get_optional_named_arg_with_user_errors(
+        INTEREST_RATE,
+        Cep18Error::InvalidInterestRate,
+    )
+    .unwrap_or_default();
+    let transfer_allow_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        TRANSFER_ALLOW_LIST,
+        Cep18Error::InvalidTransferAllowList,
+    );
+    let initial_balances: Option<Vec<(Key, U256)>> = utils::get_optional_named_arg_with_user_errors(
+        INITIAL_BALANCES,
+        Cep18Error::InvalidInitialBalances,
+    );
+    let chunked_distribution: Option<bool> = utils::get_optional_named_arg_with_user_errors(
+        CHUNKED_DISTRIBUTION,
+        Cep18Error::InvalidInitialBalances,
+    );
+
+    let admin_quorum: u8 =
+        utils::get_optional_named_arg_with_user_errors(ADMIN_QUORUM, Cep18Error::InvalidQuorum)
+            .unwrap_or(1);
+    if admin_quorum == 0 {
+        revert(Cep18Error::InvalidQuorum);
+    }
+    let mint_quorum_threshold: U256 = utils::get_optional_named_arg_with_user_errors(
+        MINT_QUORUM_THRESHOLD,
+        Cep18Error::InvalidQuorum,
+    )
+    .unwrap_or(U256::MAX);
+    let default_operators: Vec<Key> = utils::get_optional_named_arg_with_user_errors(
+        DEFAULT_OPERATORS,
+        Cep18Error::InvalidDefaultOperators,
+    )
+    .unwrap_or_default();
+    let recovery_delay: u64 = utils::get_optional_named_arg_with_user_errors(
+        RECOVERY_DELAY,
+        Cep18Error::InvalidRecoveryDelay,
+    )
+    .unwrap_or(DEFAULT_RECOVERY_DELAY);
+    let description: String =
+        utils::get_optional_named_arg_with_user_errors(DESCRIPTION, Cep18Error::InvalidMetadata)
+            .unwrap_or_default();
+    let icon_url: String =
+        utils::get_optional_named_arg_with_user_errors(ICON_URL, Cep18Error::InvalidMetadata)
+            .unwrap_or_default();
+    let website: String =
+        utils::get_optional_named_arg_with_user_errors(WEBSITE, Cep18Error::InvalidMetadata)
+            .unwrap_or_default();
+    let metadata: BTreeMap<String, String> =
+        utils::get_optional_named_arg_with_user_errors(METADATA, Cep18Error::InvalidMetadata)
+            .unwrap_or_default();
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(NAME.to_string(), storage::new_uref(name.clone()).into());
+    named_keys.insert(SYMBOL.to_string(), storage::new_uref(symbol).into());
+    named_keys.insert(DECIMALS.to_string(), storage::new_uref(decimals).into());
+    named_keys.insert(
+        DESCRIPTION.to_string(),
+        storage::new_uref(description).into(),
+    );
+    named_keys.insert(ICON_URL.to_string(), storage::new_uref(icon_url).into());
+    named_keys.insert(WEBSITE.to_string(), storage::new_uref(website).into());
+    named_keys.insert(METADATA.to_string(), storage::new_uref(metadata).into());
+    named_keys.insert(
+        TOTAL_SUPPLY.to_string(),
+        storage::new_uref(total_supply).into(),
+    );
+    named_keys.insert(
+        EVENTS_MODE.to_string(),
+        storage::new_uref(events_mode).into(),
+    );
+    named_keys.insert(
+        ENABLE_MINT_BURN.to_string(),
+        storage::new_uref(enable_mint_burn).into(),
+    );
+    named_keys.insert(
+        TRANSFER_MODE.to_string(),
+        storage::new_uref(transfer_mode).into(),
+    );
+    named_keys.insert(
+        TRANSFERABILITY.to_string(),
+        storage::new_uref(transferability).into(),
+    );
+    named_keys.insert(
+        ENFORCEMENT_MODE.to_string(),
+        storage::new_uref(enforcement_mode).into(),
+    );
+    named_keys.insert(
+        INTEREST_MODE.to_string(),
+        storage::new_uref(interest_mode).into(),
+    );
+    named_keys.insert(
+        INTEREST_RATE.to_string(),
+        storage::new_uref(interest_rate).into(),
+    );
+    named_keys.insert(
+        ACCRUAL_INDEX.to_string(),
+        storage::new_uref(U256::from(interest::INDEX_SCALE)).into(),
+    );
+    named_keys.insert(
+        LAST_ACCRUAL.to_string(),
+        storage::new_uref(u64::from(runtime::get_blocktime())).into(),
+    );
+    named_keys.insert(
+        ADMIN_QUORUM.to_string(),
+        storage::new_uref(admin_quorum).into(),
+    );
+    named_keys.insert(
+        MINT_QUORUM_THRESHOLD.to_string(),
+        storage::new_uref(mint_quorum_threshold).into(),
+    );
+    named_keys.insert(
+        DEFAULT_OPERATORS.to_string(),
+        storage::new_uref(default_operators).into(),
+    );
+    named_keys.insert(
+        RECOVERY_DELAY.to_string(),
+        storage::new_uref(recovery_delay).into(),
+    );
+    named_keys.insert(
+        MINT_RATE_LIMIT.to_string(),
+        storage::new_uref((0u64, U256::zero())).into(),
+    );
+    named_keys.insert(
+        MINT_EPOCH_USAGE.to_string(),
+        storage::new_uref((0u64, U256::zero())).into(),
+    );
+    named_keys.insert(CLAIMS_COUNT.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(STREAMS_COUNT.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(HTLCS_COUNT.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(EVENTS_LENGTH.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(
+        SCHEMA_VERSION.to_string(),
+        storage::new_uref(STORAGE_SCHEMA_VERSION).into(),
+    );
+    named_keys.insert(
+        UNDISTRIBUTED_SUPPLY.to_string(),
+        storage::new_uref(total_supply).into(),
+    );
+    let entry_points = generate_entry_points();
+
+    let hash_key_name = format!("{HASH_KEY_NAME_PREFIX}{name}");
+    let access_key_name = format!("{ACCESS_KEY_NAME_PREFIX}{name}");
+    let contract_key_name = format!("{CONTRACT_NAME_PREFIX}{name}");
+    let contract_version_key_name = format!("{CONTRACT_VERSION_PREFIX}{name}");
+    // Installing twice under the same name would otherwise overwrite the keys of the first token
+    for key_name in [
+        &hash_key_name,
+        &access_key_name,
+        &contract_key_name,
+        &contract_version_key_name,
+    ]
+    .iter()
+    {
+        if runtime::has_key(key_name) {
+            revert(Cep18Error::NamedKeyAlreadyExists);
+        }
+    }
+
+    let (contract_hash, contract_version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(hash_key_name.clone()),
+        Some(access_key_name),
+    );
+    let package_hash = runtime::get_key(&hash_key_name).unwrap_or_revert();
+
+    // Store contract_hash and contract_version under the keys CONTRACT_NAME and CONTRACT_VERSION
+    runtime::put_key(&contract_key_name, contract_hash.into());
+    runtime::put_key(
+        &contract_version_key_name,
+        storage::new_uref(contract_version).into(),
+    );
+    // Call contract to initialize it
+    let mut init_args = runtime_args! {TOTAL_SUPPLY => total_supply, PACKAGE_HASH => package_hash};
+
+    if let Some(admin_list) = admin_list {
+        init_args.insert(ADMIN_LIST, admin_list).unwrap_or_revert();
+    }
+    if let Some(minter_list) = minter_list {
+        init_args
+            .insert(MINTER_LIST, minter_list)
+            .unwrap_or_revert();
+    }
+    if let Some(master_minter_list) = master_minter_list {
+        init_args
+            .insert(MASTER_MINTER_LIST, master_minter_list)
+            .unwrap_or_revert();
+    }
+    if let Some(kyc_operator_list) = kyc_operator_list {
+        init_args
+            .insert(KYC_OPERATOR_LIST, kyc_operator_list)
+            .unwrap_or_revert();
+    }
+    if let Some(enforcer_list) = enforcer_list {
+        init_args
+            .insert(ENFORCER_LIST, enforcer_list)
+            .unwrap_or_revert();
+    }
+    if let Some(notary_list) = notary_list {
+        init_args
+            .insert(NOTARY_LIST, notary_list)
+            .unwrap_or_revert();
+    }
+    if let Some(transfer_allow_list) = transfer_allow_list {
+        init_args
+            .insert(TRANSFER_ALLOW_LIST, transfer_allow_list)
+            .unwrap_or_revert();
+    }
+    if let Some(initial_balances) = initial_balances {
+        init_args
+            .insert(INITIAL_BALANCES, initial_balances)
+            .unwrap_or_revert();
+    }
+    if let Some(chunked_distribution) = chunked_distribution {
+        init_args
+            .insert(CHUNKED_DISTRIBUTION, chunked_distribution)
             .unwrap_or_revert();
     }
 
@@ -0,0 +1,49 @@
+//! Implementation of minting allowances.
+use alloc::string::String;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{bytesrepr::ToBytes, Key, URef, U256};
+
+use crate::{constants::MINTER_ALLOWANCE, error::Cep18Error, utils};
+
+/// Creates a dictionary item key for a minter.
+#[inline]
+fn make_dictionary_item_key(minter: Key) -> String {
+    base64::encode(minter.to_bytes().unwrap_or_revert())
+}
+
+/// Getter for the "minter_allowance" dictionary URef.
+#[inline]
+pub(crate) fn get_minter_allowance_uref() -> URef {
+    utils::get_uref(MINTER_ALLOWANCE)
+}
+
+/// Writes the minting allowance of a minter.
+pub(crate) fn write_minter_allowance_to(minter_allowance_uref: URef, minter: Key, amount: U256) {
+    storage::dictionary_put(
+        minter_allowance_uref,
+        &make_dictionary_item_key(minter),
+        amount,
+    )
+}
+
+/// Reads the minting allowance of a minter.
+///
+/// Minters which were never configured by a master minter, such as those granted at
+/// installation or through `change_security`, have no allowance and can mint without limit.
+pub(crate) fn read_minter_allowance_from(minter_allowance_uref: URef, minter: Key) -> Option<U256> {
+    storage::dictionary_get(minter_allowance_uref, &make_dictionary_item_key(minter))
+        .unwrap_or_revert()
+}
+
+/// Decreases the minting allowance of a configured minter by `amount`.
+pub(crate) fn spend_minter_allowance(minter: Key, amount: U256) -> Result<(), Cep18Error> {
+    let minter_allowance_uref = get_minter_allowance_uref();
+    if let Some(allowance) = read_minter_allowance_from(minter_allowance_uref, minter) {
+        let new_allowance = allowance
+            .checked_sub(amount)
+            .ok_or(Cep18Error::MinterAllowanceExceeded)?;
+        write_minter_allowance_to(minter_allowance_uref, minter, new_allowance);
+    }
+    Ok(())
+}
@@ -8,6 +8,20 @@ use crate::Cep18Error;
 pub enum EventsMode {
     NoEvents = 0,
     CES = 1,
+    Native = 2,
+    NativeAndCES = 3,
+}
+
+impl EventsMode {
+    /// Returns whether events are recorded following the Casper Event Standard.
+    pub fn records_ces(&self) -> bool {
+        matches!(self, EventsMode::CES | EventsMode::NativeAndCES)
+    }
+
+    /// Returns whether events are appended to the native event log.
+    pub fn records_native(&self) -> bool {
+        matches!(self, EventsMode::Native | EventsMode::NativeAndCES)
+    }
 }
 
 impl TryFrom<u8> for EventsMode {
@@ -17,6 +31,8 @@ impl TryFrom<u8> for EventsMode {
         match value {
             0 => Ok(EventsMode::NoEvents),
             1 => Ok(EventsMode::CES),
+            2 => Ok(EventsMode::Native),
+            3 => Ok(EventsMode::NativeAndCES),
             _ => Err(Cep18Error::InvalidEventsMode),
         }
     }
@@ -41,3 +57,81 @@ impl TryFrom<u8> for MintBurn {
         }
     }
 }
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+pub enum TransferMode {
+    Open = 0,
+    Whitelist = 1,
+}
+
+impl TryFrom<u8> for TransferMode {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TransferMode::Open),
+            1 => Ok(TransferMode::Whitelist),
+            _ => Err(Cep18Error::InvalidTransferMode),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+pub enum EnforcementMode {
+    Disabled = 0,
+    Enabled = 1,
+    OverrideFreeze = 2,
+}
+
+impl TryFrom<u8> for EnforcementMode {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(EnforcementMode::Disabled),
+            1 => Ok(EnforcementMode::Enabled),
+            2 => Ok(EnforcementMode::OverrideFreeze),
+            _ => Err(Cep18Error::InvalidEnforcementMode),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+pub enum Transferability {
+    Transferable = 0,
+    NonTransferable = 1,
+}
+
+impl TryFrom<u8> for Transferability {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Transferability::Transferable),
+            1 => Ok(Transferability::NonTransferable),
+            _ => Err(Cep18Error::InvalidTransferability),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+pub enum InterestMode {
+    Disabled = 0,
+    Accruing = 1,
+}
+
+impl TryFrom<u8> for InterestMode {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(InterestMode::Disabled),
+            1 => Ok(InterestMode::Accruing),
+            _ => Err(Cep18Error::InvalidInterestMode),
+        }
+    }
+}
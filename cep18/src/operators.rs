@@ -0,0 +1,44 @@
+//! Implementation of operators, account-wide delegates following ERC-777.
+use alloc::vec::Vec;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{Key, URef};
+
+use crate::{
+    allowances::make_dictionary_item_key,
+    constants::{DEFAULT_OPERATORS, OPERATORS},
+    utils,
+};
+
+/// Getter for the "operators" dictionary URef.
+#[inline]
+pub(crate) fn get_operators_uref() -> URef {
+    utils::get_uref(OPERATORS)
+}
+
+/// Writes whether `operator` is authorized by `holder`.
+pub(crate) fn write_operator_to(
+    operators_uref: URef,
+    holder: Key,
+    operator: Key,
+    authorized: bool,
+) {
+    storage::dictionary_put(
+        operators_uref,
+        &make_dictionary_item_key(holder, operator),
+        authorized,
+    )
+}
+
+/// Returns whether `operator` can move any amount of the tokens of `holder`.
+///
+/// Holders are operators for themselves, and default operators set at installation are
+/// authorized until the holder revokes them.
+pub(crate) fn is_operator_for(operators_uref: URef, operator: Key, holder: Key) -> bool {
+    if operator == holder {
+        return true;
+    }
+    storage::dictionary_get(operators_uref, &make_dictionary_item_key(holder, operator))
+        .unwrap_or_revert()
+        .unwrap_or_else(|| utils::read_from::<Vec<Key>>(DEFAULT_OPERATORS).contains(&operator))
+}
@@ -0,0 +1,113 @@
+//! Implementation of the admin quorum guarding privileged entry points.
+use alloc::{string::String, vec::Vec};
+use core::convert::TryFrom;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, URef};
+
+use crate::{
+    constants::{ACTION_APPROVALS, ADMIN_QUORUM},
+    error::Cep18Error,
+    utils::{self, SecurityBadge},
+};
+
+#[inline]
+pub(crate) fn get_action_approvals_uref() -> URef {
+    utils::get_uref(ACTION_APPROVALS)
+}
+
+/// Computes the hash identifying a privileged action.
+///
+/// The preimage is the entry point name followed by the serialized arguments of the action, so
+/// approvers can compute it off-chain before the action is executed.
+pub(crate) fn make_action_hash(entry_point: &str, args: &[u8]) -> [u8; 32] {
+    let mut preimage = Vec::new();
+    preimage.extend_from_slice(entry_point.as_bytes());
+    preimage.extend_from_slice(args);
+    runtime::blake2b(preimage)
+}
+
+/// Creates a dictionary item key for an action hash.
+#[inline]
+fn make_dictionary_item_key(action_hash: [u8; 32]) -> String {
+    hex::encode(action_hash)
+}
+
+/// Reads the keys which approved a given action.
+pub(crate) fn read_approvals_from(approvals_uref: URef, action_hash: [u8; 32]) -> Vec<Key> {
+    storage::dictionary_get(approvals_uref, &make_dictionary_item_key(action_hash))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Writes the keys which approved a given action.
+pub(crate) fn write_approvals_to(approvals_uref: URef, action_hash: [u8; 32], approvals: Vec<Key>) {
+    storage::dictionary_put(
+        approvals_uref,
+        &make_dictionary_item_key(action_hash),
+        approvals,
+    )
+}
+
+/// Counts the approvals given by keys which are still admins.
+fn count_admin_approvals(approvals: &[Key]) -> u8 {
+    let count = approvals
+        .iter()
+        .filter(|approver| utils::get_sec_badge(**approver) == Some(SecurityBadge::Admin))
+        .count();
+    u8::try_from(count).unwrap_or(u8::MAX)
+}
+
+/// Checks that enough admins exist to reach `quorum`, so privileged actions cannot be locked out.
+pub(crate) fn check_admin_count(quorum: u8) -> Result<(), Cep18Error> {
+    if u32::from(quorum) > utils::read_role_member_count(SecurityBadge::Admin) {
+        return Err(Cep18Error::QuorumExceedsAdmins);
+    }
+    Ok(())
+}
+
+/// Records the approval of an action by `approver` and returns the number of admin approvals.
+pub(crate) fn approve_action(approver: Key, action_hash: [u8; 32]) -> Result<u8, Cep18Error> {
+    let approvals_uref = get_action_approvals_uref();
+    let mut approvals = read_approvals_from(approvals_uref, action_hash);
+    if approvals.contains(&approver) {
+        return Err(Cep18Error::ActionAlreadyApproved);
+    }
+    approvals.push(approver);
+    let count = count_admin_approvals(&approvals);
+    write_approvals_to(approvals_uref, action_hash, approvals);
+    Ok(count)
+}
+
+/// Checks the quorum of the immediate caller executing `entry_point` with the serialized `args`,
+/// see `make_action_hash`.
+pub(crate) fn check_action_quorum(entry_point: &str, args: &[u8]) -> Result<(), Cep18Error> {
+    check_quorum(
+        utils::get_immediate_caller_address()?,
+        make_action_hash(entry_point, args),
+    )
+}
+
+/// Checks that enough admins approved an action and consumes their approvals.
+///
+/// The executing `caller` counts as an approval when they are an admin. With a quorum of one the
+/// check always passes, as the entry points already require the caller to hold a badge.
+pub(crate) fn check_quorum(caller: Key, action_hash: [u8; 32]) -> Result<(), Cep18Error> {
+    let quorum = utils::read_from::<u8>(ADMIN_QUORUM);
+    if quorum <= 1 {
+        return Ok(());
+    }
+    let approvals_uref = get_action_approvals_uref();
+    let mut approvals = read_approvals_from(approvals_uref, action_hash);
+    if !approvals.contains(&caller) {
+        approvals.push(caller);
+    }
+    if count_admin_approvals(&approvals) < quorum {
+        return Err(Cep18Error::QuorumNotReached);
+    }
+    write_approvals_to(approvals_uref, action_hash, Vec::new());
+    Ok(())
+}
@@ -0,0 +1,139 @@
+//! Implementation of the per epoch minting rate limits.
+//!
+//! A rate limit is stored as an `(epoch_duration, epoch_limit)` pair, the duration being in
+//! milliseconds of block time. Epochs are fixed windows starting at multiples of the duration, and
+//! a duration of zero disables the limit. The amount minted during the current epoch is tracked
+//! as an `(epoch, minted)` pair.
+use alloc::string::String;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, Key, URef, U256};
+
+use crate::{
+    constants::{MINTER_EPOCH_USAGE, MINTER_RATE_LIMITS, MINT_EPOCH_USAGE, MINT_RATE_LIMIT},
+    error::Cep18Error,
+    utils,
+};
+
+/// Creates a dictionary item key for a minter.
+#[inline]
+fn make_dictionary_item_key(minter: Key) -> String {
+    base64::encode(minter.to_bytes().unwrap_or_revert())
+}
+
+/// Writes the rate limit applying to all mints.
+pub(crate) fn write_global_rate_limit(epoch_duration: u64, epoch_limit: U256) {
+    storage::write(
+        utils::get_uref(MINT_RATE_LIMIT),
+        (epoch_duration, epoch_limit),
+    );
+}
+
+/// Writes the rate limit applying to the mints of a specific minter.
+pub(crate) fn write_minter_rate_limit(minter: Key, epoch_duration: u64, epoch_limit: U256) {
+    storage::dictionary_put(
+        utils::get_uref(MINTER_RATE_LIMITS),
+        &make_dictionary_item_key(minter),
+        (epoch_duration, epoch_limit),
+    );
+}
+
+fn read_minter_rate_limit(minter_rate_limits_uref: URef, minter: Key) -> Option<(u64, U256)> {
+    storage::dictionary_get(minter_rate_limits_uref, &make_dictionary_item_key(minter))
+        .unwrap_or_revert()
+}
+
+fn read_minter_epoch_usage(minter_epoch_usage_uref: URef, minter: Key) -> Option<(u64, U256)> {
+    storage::dictionary_get(minter_epoch_usage_uref, &make_dictionary_item_key(minter))
+        .unwrap_or_revert()
+}
+
+/// Returns the epoch the current block time falls into, `None` if the limit is disabled.
+fn current_epoch(epoch_duration: u64) -> Option<u64> {
+    if epoch_duration == 0 {
+        return None;
+    }
+    Some(u64::from(runtime::get_blocktime()) / epoch_duration)
+}
+
+/// Returns the amount which can still be minted during the current epoch.
+fn remaining_capacity(rate_limit: (u64, U256), epoch_usage: Option<(u64, U256)>) -> U256 {
+    let (epoch_duration, epoch_limit) = rate_limit;
+    match (current_epoch(epoch_duration), epoch_usage) {
+        (None, _) => U256::MAX,
+        (Some(epoch), Some((usage_epoch, minted))) if epoch == usage_epoch => {
+            epoch_limit.saturating_sub(minted)
+        }
+        (Some(_), _) => epoch_limit,
+    }
+}
+
+/// Returns the updated epoch usage after minting `amount`, `None` if the limit is disabled.
+fn spend_capacity(
+    rate_limit: (u64, U256),
+    epoch_usage: Option<(u64, U256)>,
+    amount: U256,
+) -> Result<Option<(u64, U256)>, Cep18Error> {
+    let (epoch_duration, epoch_limit) = rate_limit;
+    let epoch = match current_epoch(epoch_duration) {
+        Some(epoch) => epoch,
+        None => return Ok(None),
+    };
+    let minted = match epoch_usage {
+        Some((usage_epoch, minted)) if usage_epoch == epoch => minted,
+        _ => U256::zero(),
+    };
+    let new_minted = minted
+        .checked_add(amount)
+        .filter(|new_minted| *new_minted <= epoch_limit)
+        .ok_or(Cep18Error::MintRateLimitExceeded)?;
+    Ok(Some((epoch, new_minted)))
+}
+
+/// Returns the amount `minter` can still mint during the current epochs, taking both the global
+/// and the minter rate limits into account.
+pub(crate) fn remaining_mint_capacity(minter: Key) -> U256 {
+    let global_capacity = remaining_capacity(
+        utils::read_from(MINT_RATE_LIMIT),
+        Some(utils::read_from(MINT_EPOCH_USAGE)),
+    );
+    let minter_capacity = match read_minter_rate_limit(utils::get_uref(MINTER_RATE_LIMITS), minter)
+    {
+        Some(rate_limit) => remaining_capacity(
+            rate_limit,
+            read_minter_epoch_usage(utils::get_uref(MINTER_EPOCH_USAGE), minter),
+        ),
+        None => U256::MAX,
+    };
+    global_capacity.min(minter_capacity)
+}
+
+/// Records the minting of `amount` by `minter` against the global and the minter rate limits.
+pub(crate) fn spend_mint_capacity(minter: Key, amount: U256) -> Result<(), Cep18Error> {
+    if let Some(epoch_usage) = spend_capacity(
+        utils::read_from(MINT_RATE_LIMIT),
+        Some(utils::read_from(MINT_EPOCH_USAGE)),
+        amount,
+    )? {
+        storage::write(utils::get_uref(MINT_EPOCH_USAGE), epoch_usage);
+    }
+
+    if let Some(rate_limit) = read_minter_rate_limit(utils::get_uref(MINTER_RATE_LIMITS), minter) {
+        let minter_epoch_usage_uref = utils::get_uref(MINTER_EPOCH_USAGE);
+        if let Some(epoch_usage) = spend_capacity(
+            rate_limit,
+            read_minter_epoch_usage(minter_epoch_usage_uref, minter),
+            amount,
+        )? {
+            storage::dictionary_put(
+                minter_epoch_usage_uref,
+                &make_dictionary_item_key(minter),
+                epoch_usage,
+            );
+        }
+    }
+    Ok(())
+}
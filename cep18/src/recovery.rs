@@ -0,0 +1,78 @@
+//! Implementation of the recovery of balances held by lost keys.
+//!
+//! A pending recovery is stored as a `(new_owner, finalize_after)` pair, `finalize_after` being
+//! the block time in milliseconds at which the challenge window of the lost key ends.
+use alloc::string::String;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, Key, URef};
+
+use crate::{
+    constants::{RECOVERIES, RECOVERY_DELAY},
+    error::Cep18Error,
+    utils,
+};
+
+/// Creates a dictionary item key for a lost account.
+#[inline]
+fn make_dictionary_item_key(lost: Key) -> String {
+    base64::encode(lost.to_bytes().unwrap_or_revert())
+}
+
+/// Getter for the "recoveries" dictionary URef.
+#[inline]
+pub(crate) fn get_recoveries_uref() -> URef {
+    utils::get_uref(RECOVERIES)
+}
+
+/// Reads the pending recovery of a lost account.
+pub(crate) fn read_recovery_from(recoveries_uref: URef, lost: Key) -> Option<(Key, u64)> {
+    storage::dictionary_get(recoveries_uref, &make_dictionary_item_key(lost))
+        .unwrap_or_revert()
+        .flatten()
+}
+
+/// Writes the pending recovery of a lost account, `None` clearing it.
+pub(crate) fn write_recovery_to(recoveries_uref: URef, lost: Key, recovery: Option<(Key, u64)>) {
+    storage::dictionary_put(recoveries_uref, &make_dictionary_item_key(lost), recovery)
+}
+
+/// Opens the recovery of the balance of `lost` to `new_owner`, returning the block time at which
+/// it can be finalized.
+pub(crate) fn initiate_recovery(lost: Key, new_owner: Key) -> Result<u64, Cep18Error> {
+    let recoveries_uref = get_recoveries_uref();
+    if read_recovery_from(recoveries_uref, lost).is_some() {
+        return Err(Cep18Error::RecoveryAlreadyPending);
+    }
+    let finalize_after = u64::from(runtime::get_blocktime())
+        .checked_add(utils::read_from::<u64>(RECOVERY_DELAY))
+        .ok_or(Cep18Error::Overflow)?;
+    write_recovery_to(recoveries_uref, lost, Some((new_owner, finalize_after)));
+    Ok(finalize_after)
+}
+
+/// Aborts the pending recovery of `lost`, returning whether there was one.
+pub(crate) fn cancel_recovery(lost: Key) -> bool {
+    let recoveries_uref = get_recoveries_uref();
+    if read_recovery_from(recoveries_uref, lost).is_none() {
+        return false;
+    }
+    write_recovery_to(recoveries_uref, lost, None);
+    true
+}
+
+/// Closes the pending recovery of `lost` once its challenge window is over, returning the new
+/// owner of the balance.
+pub(crate) fn finalize_recovery(lost: Key) -> Result<Key, Cep18Error> {
+    let recoveries_uref = get_recoveries_uref();
+    let (new_owner, finalize_after) =
+        read_recovery_from(recoveries_uref, lost).ok_or(Cep18Error::NoPendingRecovery)?;
+    if u64::from(runtime::get_blocktime()) < finalize_after {
+        return Err(Cep18Error::RecoveryChallengePeriod);
+    }
+    write_recovery_to(recoveries_uref, lost, None);
+    Ok(new_owner)
+}
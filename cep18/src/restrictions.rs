@@ -0,0 +1,268 @@
+//! Implementation of the transfer restrictions, following ERC-1404.
+use alloc::string::String;
+use core::convert::TryFrom;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{bytesrepr::ToBytes, Key, URef, U256};
+
+use crate::{
+    balances::{check_spendable, get_balances_uref, read_balance_from},
+    constants::{
+        ENFORCEMENT_MODE, FROZEN, TRANSFERABILITY, TRANSFER_ALLOW_LIST, TRANSFER_MODE, WHITELIST,
+    },
+    error::Cep18Error,
+    modalities::{EnforcementMode, TransferMode, Transferability},
+    utils,
+};
+
+/// Restriction codes returned by `detect_transfer_restriction`.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TransferRestriction {
+    Success = 0,
+    SenderFrozen = 1,
+    RecipientFrozen = 2,
+    SenderNotWhitelisted = 3,
+    RecipientNotWhitelisted = 4,
+    AmountLocked = 5,
+    AmountOnHold = 6,
+    NonTransferable = 7,
+}
+
+impl TransferRestriction {
+    /// Returns the human readable message of a restriction code.
+    pub(crate) fn message_for_code(code: u8) -> &'static str {
+        match code {
+            c if c == TransferRestriction::Success as u8 => "No restriction",
+            c if c == TransferRestriction::SenderFrozen as u8 => "Sender account is frozen",
+            c if c == TransferRestriction::RecipientFrozen as u8 => "Recipient account is frozen",
+            c if c == TransferRestriction::SenderNotWhitelisted as u8 => {
+                "Sender account is not whitelisted"
+            }
+            c if c == TransferRestriction::RecipientNotWhitelisted as u8 => {
+                "Recipient account is not whitelisted"
+            }
+            c if c == TransferRestriction::AmountLocked as u8 => {
+                "Amount exceeds the unlocked balance"
+            }
+            c if c == TransferRestriction::AmountOnHold as u8 => {
+                "Amount exceeds the balance not on hold"
+            }
+            c if c == TransferRestriction::NonTransferable as u8 => "Token is non-transferable",
+            _ => "Unknown restriction code",
+        }
+    }
+}
+
+/// Creates a dictionary item key for an account.
+#[inline]
+fn make_dictionary_item_key(account: Key) -> String {
+    base64::encode(account.to_bytes().unwrap_or_revert())
+}
+
+/// Getter for the "frozen" dictionary URef.
+#[inline]
+pub(crate) fn get_frozen_uref() -> URef {
+    utils::get_uref(FROZEN)
+}
+
+/// Writes whether an account is frozen.
+pub(crate) fn write_frozen_to(frozen_uref: URef, account: Key, frozen: bool) {
+    storage::dictionary_put(frozen_uref, &make_dictionary_item_key(account), frozen)
+}
+
+/// Reads whether an account is frozen.
+pub(crate) fn read_frozen_from(frozen_uref: URef, account: Key) -> bool {
+    storage::dictionary_get(frozen_uref, &make_dictionary_item_key(account))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Getter for the "whitelist" dictionary URef.
+#[inline]
+pub(crate) fn get_whitelist_uref() -> URef {
+    utils::get_uref(WHITELIST)
+}
+
+/// Writes whether an account is whitelisted.
+pub(crate) fn write_whitelisted_to(whitelist_uref: URef, account: Key, whitelisted: bool) {
+    storage::dictionary_put(
+        whitelist_uref,
+        &make_dictionary_item_key(account),
+        whitelisted,
+    )
+}
+
+/// Reads whether an account is whitelisted.
+pub(crate) fn read_whitelisted_from(whitelist_uref: URef, account: Key) -> bool {
+    storage::dictionary_get(whitelist_uref, &make_dictionary_item_key(account))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Returns whether the whitelist must be enforced, according to the `transfer_mode` modality.
+pub(crate) fn is_whitelist_enforced() -> bool {
+    TransferMode::try_from(utils::read_from::<u8>(TRANSFER_MODE)).unwrap_or_revert()
+        == TransferMode::Whitelist
+}
+
+/// Checks that `account` can receive tokens outside of a transfer, i.e. when minting.
+pub(crate) fn check_recipient_whitelisted(account: Key) -> Result<(), Cep18Error> {
+    if is_whitelist_enforced() && !read_whitelisted_from(get_whitelist_uref(), account) {
+        return Err(Cep18Error::RecipientNotWhitelisted);
+    }
+    Ok(())
+}
+
+/// Checks that `account` can send tokens outside of a transfer, i.e. when burning.
+pub(crate) fn check_sender_whitelisted(account: Key) -> Result<(), Cep18Error> {
+    if is_whitelist_enforced() && !read_whitelisted_from(get_whitelist_uref(), account) {
+        return Err(Cep18Error::SenderNotWhitelisted);
+    }
+    Ok(())
+}
+
+/// Getter for the "transfer_allow_list" dictionary URef.
+#[inline]
+pub(crate) fn get_transfer_allow_list_uref() -> URef {
+    utils::get_uref(TRANSFER_ALLOW_LIST)
+}
+
+/// Writes whether an account is exempted from non-transferability.
+pub(crate) fn write_transfer_allowed_to(
+    transfer_allow_list_uref: URef,
+    account: Key,
+    allowed: bool,
+) {
+    storage::dictionary_put(
+        transfer_allow_list_uref,
+        &make_dictionary_item_key(account),
+        allowed,
+    )
+}
+
+/// Reads whether an account is exempted from non-transferability.
+pub(crate) fn read_transfer_allowed_from(transfer_allow_list_uref: URef, account: Key) -> bool {
+    storage::dictionary_get(transfer_allow_list_uref, &make_dictionary_item_key(account))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Returns whether tokens are soulbound, according to the `transferability` modality.
+pub(crate) fn is_non_transferable() -> bool {
+    Transferability::try_from(utils::read_from::<u8>(TRANSFERABILITY)).unwrap_or_revert()
+        == Transferability::NonTransferable
+}
+
+/// Checks that `spender` can be granted an allowance or become an operator, which is only
+/// possible for allow-listed accounts when tokens are soulbound.
+pub(crate) fn check_delegation_allowed(spender: Key) -> Result<(), Cep18Error> {
+    if is_non_transferable() && !read_transfer_allowed_from(get_transfer_allow_list_uref(), spender)
+    {
+        return Err(Cep18Error::NonTransferable);
+    }
+    Ok(())
+}
+
+/// Returns the restriction preventing any amount from moving between `from` and `to`, if any,
+/// regardless of the balance of `from`.
+fn detect_counterparty_restriction(from: Key, to: Key) -> TransferRestriction {
+    // Tokens going back to their owner, e.g. reclaimed from an escrow, are not transferred.
+    if from != to && is_non_transferable() {
+        let transfer_allow_list_uref = get_transfer_allow_list_uref();
+        if !read_transfer_allowed_from(transfer_allow_list_uref, from)
+            && !read_transfer_allowed_from(transfer_allow_list_uref, to)
+        {
+            return TransferRestriction::NonTransferable;
+        }
+    }
+    let frozen_uref = get_frozen_uref();
+    if read_frozen_from(frozen_uref, from) {
+        return TransferRestriction::SenderFrozen;
+    }
+    if read_frozen_from(frozen_uref, to) {
+        return TransferRestriction::RecipientFrozen;
+    }
+    if is_whitelist_enforced() {
+        let whitelist_uref = get_whitelist_uref();
+        if !read_whitelisted_from(whitelist_uref, from) {
+            return TransferRestriction::SenderNotWhitelisted;
+        }
+        if !read_whitelisted_from(whitelist_uref, to) {
+            return TransferRestriction::RecipientNotWhitelisted;
+        }
+    }
+    TransferRestriction::Success
+}
+
+/// Returns the restriction preventing `amount` from moving between `from` and `to`, if any.
+pub(crate) fn detect_transfer_restriction(from: Key, to: Key, amount: U256) -> TransferRestriction {
+    let restriction = detect_counterparty_restriction(from, to);
+    if restriction != TransferRestriction::Success {
+        return restriction;
+    }
+    let balance = read_balance_from(get_balances_uref(), from);
+    if amount <= balance {
+        match check_spendable(from, balance - amount) {
+            Err(Cep18Error::AmountLocked) => return TransferRestriction::AmountLocked,
+            Err(Cep18Error::AmountOnHold) => return TransferRestriction::AmountOnHold,
+            _ => {}
+        }
+    }
+    TransferRestriction::Success
+}
+
+/// Checks that `amount` can move between `from` and `to`.
+pub(crate) fn check_transfer_restriction(
+    from: Key,
+    to: Key,
+    amount: U256,
+) -> Result<(), Cep18Error> {
+    restriction_to_result(detect_transfer_restriction(from, to, amount))
+}
+
+/// Checks that tokens escrowed by `from` can be released to `to`.
+///
+/// Escrowed tokens are held by the contract, so only the accounts on both sides of the escrow are
+/// checked, not the balance of `from`.
+pub(crate) fn check_release_restriction(from: Key, to: Key) -> Result<(), Cep18Error> {
+    restriction_to_result(detect_counterparty_restriction(from, to))
+}
+
+/// Maps a restriction to the error it reverts with.
+fn restriction_to_result(restriction: TransferRestriction) -> Result<(), Cep18Error> {
+    match restriction {
+        TransferRestriction::Success => Ok(()),
+        TransferRestriction::SenderFrozen => Err(Cep18Error::SenderFrozen),
+        TransferRestriction::RecipientFrozen => Err(Cep18Error::RecipientFrozen),
+        TransferRestriction::SenderNotWhitelisted => Err(Cep18Error::SenderNotWhitelisted),
+        TransferRestriction::RecipientNotWhitelisted => Err(Cep18Error::RecipientNotWhitelisted),
+        TransferRestriction::AmountLocked => Err(Cep18Error::AmountLocked),
+        TransferRestriction::AmountOnHold => Err(Cep18Error::AmountOnHold),
+        TransferRestriction::NonTransferable => Err(Cep18Error::NonTransferable),
+    }
+}
+
+/// Checks that an enforcer can move tokens out of `from`, and into `to` for forced transfers.
+///
+/// Allowances and locks are not checked here, frozen accounts are only skipped when the
+/// `enforcement_mode` modality overrides freezes.
+pub(crate) fn check_enforcement_restriction(from: Key, to: Option<Key>) -> Result<(), Cep18Error> {
+    let enforcement_mode = EnforcementMode::try_from(utils::read_from::<u8>(ENFORCEMENT_MODE))?;
+    if enforcement_mode == EnforcementMode::Disabled {
+        return Err(Cep18Error::EnforcementDisabled);
+    }
+    if enforcement_mode != EnforcementMode::OverrideFreeze {
+        let frozen_uref = get_frozen_uref();
+        if read_frozen_from(frozen_uref, from) {
+            return Err(Cep18Error::SenderFrozen);
+        }
+        if to.map_or(false, |to| read_frozen_from(frozen_uref, to)) {
+            return Err(Cep18Error::RecipientFrozen);
+        }
+    }
+    match to {
+        Some(to) => check_recipient_whitelisted(to),
+        None => Ok(()),
+    }
+}
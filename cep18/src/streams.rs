@@ -0,0 +1,153 @@
+//! Implementation of payment streams, releasing a deposit held in the custody of the contract
+//! linearly to their recipient.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key, URef, U256,
+};
+
+use crate::{
+    constants::{STREAMS, STREAMS_COUNT},
+    error::Cep18Error,
+    utils,
+};
+
+/// A `deposit` of `sender` streamed linearly to `recipient` between the `start` and `stop` block
+/// times, `withdrawn` being the part already received by the recipient. The contract holds
+/// `scaled_deposit`, the amount stored for the deposit in its balance.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Stream {
+    pub sender: Key,
+    pub recipient: Key,
+    pub deposit: U256,
+    pub scaled_deposit: U256,
+    pub start: u64,
+    pub stop: u64,
+    pub withdrawn: U256,
+}
+
+impl Stream {
+    /// Returns the part of the deposit streamed at block time `now`.
+    pub(crate) fn streamed_at(&self, now: u64) -> U256 {
+        if now <= self.start {
+            return U256::zero();
+        }
+        if now >= self.stop {
+            return self.deposit;
+        }
+        // Split the product to avoid overflowing with large deposits.
+        let duration = U256::from(self.stop - self.start);
+        let elapsed = U256::from(now - self.start);
+        self.deposit / duration * elapsed + self.deposit % duration * elapsed / duration
+    }
+
+    /// Returns the stored amount held for the nominal part `amount` of the deposit, rounded down.
+    pub(crate) fn scaled_part(&self, amount: U256) -> U256 {
+        // Split the product to avoid overflowing with large deposits.
+        self.scaled_deposit / self.deposit * amount
+            + self.scaled_deposit % self.deposit * amount / self.deposit
+    }
+
+    /// Returns the part of the deposit the recipient can withdraw at block time `now`.
+    pub(crate) fn withdrawable_at(&self, now: u64) -> U256 {
+        self.streamed_at(now) - self.withdrawn
+    }
+}
+
+impl CLTyped for Stream {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Stream {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.sender.to_bytes()?);
+        result.append(&mut self.recipient.to_bytes()?);
+        result.append(&mut self.deposit.to_bytes()?);
+        result.append(&mut self.scaled_deposit.to_bytes()?);
+        result.append(&mut self.start.to_bytes()?);
+        result.append(&mut self.stop.to_bytes()?);
+        result.append(&mut self.withdrawn.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.sender.serialized_length()
+            + self.recipient.serialized_length()
+            + self.deposit.serialized_length()
+            + self.scaled_deposit.serialized_length()
+            + self.start.serialized_length()
+            + self.stop.serialized_length()
+            + self.withdrawn.serialized_length()
+    }
+}
+
+impl FromBytes for Stream {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (sender, remainder) = Key::from_bytes(bytes)?;
+        let (recipient, remainder) = Key::from_bytes(remainder)?;
+        let (deposit, remainder) = U256::from_bytes(remainder)?;
+        let (scaled_deposit, remainder) = U256::from_bytes(remainder)?;
+        let (start, remainder) = u64::from_bytes(remainder)?;
+        let (stop, remainder) = u64::from_bytes(remainder)?;
+        let (withdrawn, remainder) = U256::from_bytes(remainder)?;
+        Ok((
+            Stream {
+                sender,
+                recipient,
+                deposit,
+                scaled_deposit,
+                start,
+                stop,
+                withdrawn,
+            },
+            remainder,
+        ))
+    }
+}
+
+/// Creates a dictionary item key for a stream id.
+#[inline]
+fn make_dictionary_item_key(stream_id: u64) -> String {
+    hex::encode(stream_id.to_bytes().unwrap_or_revert())
+}
+
+/// Getter for the "streams" dictionary URef.
+#[inline]
+pub(crate) fn get_streams_uref() -> URef {
+    utils::get_uref(STREAMS)
+}
+
+/// Reads the open stream of a stream id.
+pub(crate) fn read_stream_from(streams_uref: URef, stream_id: u64) -> Option<Stream> {
+    storage::dictionary_get(streams_uref, &make_dictionary_item_key(stream_id))
+        .unwrap_or_revert()
+        .flatten()
+}
+
+/// Writes the stream of a stream id, `None` closing it.
+pub(crate) fn write_stream_to(streams_uref: URef, stream_id: u64, stream: Option<Stream>) {
+    storage::dictionary_put(streams_uref, &make_dictionary_item_key(stream_id), stream)
+}
+
+/// Records a stream under a new stream id, returning it. The deposit has to be moved to the
+/// contract by the caller.
+pub(crate) fn create_stream(stream: Stream) -> Result<u64, Cep18Error> {
+    if stream.deposit.is_zero() || stream.stop <= stream.start {
+        return Err(Cep18Error::InvalidStreamSchedule);
+    }
+    let streams_count_uref = utils::get_uref(STREAMS_COUNT);
+    let stream_id: u64 = storage::read(streams_count_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    storage::write(
+        streams_count_uref,
+        stream_id.checked_add(1).ok_or(Cep18Error::Overflow)?,
+    );
+    write_stream_to(get_streams_uref(), stream_id, Some(stream));
+    Ok(stream_id)
+}
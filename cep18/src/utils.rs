@@ -1,7 +1,13 @@
 //! Implementation details.
 use core::convert::TryInto;
 
-use alloc::{collections::BTreeMap, vec, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use casper_contract::{
     contract_api::{
         self,
@@ -19,8 +25,12 @@ use casper_types::{
 };
 
 use crate::{
-    constants::{SECURITY_BADGES, TOTAL_SUPPLY},
+    constants::{
+        MAX_DECIMALS, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, PACKAGE_HASH, ROLE_MEMBERS,
+        ROLE_MEMBER_INDICES, SECURITY_BADGES, TOTAL_SUPPLY,
+    },
     error::Cep18Error,
+    interest,
 };
 
 /// Gets [`URef`] under a name.
@@ -73,16 +83,39 @@ pub(crate) fn get_immediate_caller_address() -> Result<Key, Cep18Error> {
         .ok_or(Cep18Error::InvalidContext)
 }
 
+/// Returns the key of the contract package, holding the balances in the custody of the contract.
+pub(crate) fn get_package_key() -> Key {
+    runtime::get_key(PACKAGE_HASH).unwrap_or_revert_with(Cep18Error::PackageHashMissing)
+}
+
+/// Rejects the key of the contract package, so the balances in custody can only be moved by the
+/// entry points escrowing them.
+pub(crate) fn check_not_custody(key: Key) -> Result<(), Cep18Error> {
+    if key == get_package_key() {
+        return Err(Cep18Error::CustodyKeyRestricted);
+    }
+    Ok(())
+}
+
 pub fn get_total_supply_uref() -> URef {
     get_uref(TOTAL_SUPPLY)
 }
 
 pub(crate) fn read_total_supply_from(uref: URef) -> U256 {
+    interest::to_nominal(read_scaled_total_supply_from(uref))
+}
+
+/// Reads the stored total supply from a specific [`URef`].
+pub(crate) fn read_scaled_total_supply_from(uref: URef) -> U256 {
     storage::read(uref).unwrap_or_revert().unwrap_or_revert()
 }
 
-/// Writes a total supply to a specific [`URef`].
-pub(crate) fn write_total_supply_to(uref: URef, value: U256) {
+/// Writes a stored total supply to a specific [`URef`].
+///
+/// An interest-bearing total supply is stored as an amount scaled by the accrual index, matching
+/// the sum of the stored balances.
+pub(crate) fn write_scaled_total_supply_to(uref: URef, value: U256) {
+    interest::accrue();
     storage::write(uref, value);
 }
 
@@ -143,12 +176,55 @@ pub fn get_named_arg_with_user_errors<T: FromBytes>(
     bytesrepr::deserialize(arg_bytes).map_err(|_| invalid)
 }
 
+/// Validates the token metadata. The name is spliced into the named keys of the installing
+/// account, hence restricted to ASCII alphanumeric characters, spaces, `-` and `_`, while the
+/// symbol is restricted to ASCII alphanumeric characters.
+pub(crate) fn validate_metadata(name: &str, symbol: &str, decimals: u8) -> Result<(), Cep18Error> {
+    if name.is_empty()
+        || name.len() > MAX_NAME_LENGTH
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '-' || c == '_')
+    {
+        return Err(Cep18Error::InvalidName);
+    }
+    if symbol.is_empty()
+        || symbol.len() > MAX_SYMBOL_LENGTH
+        || !symbol.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        return Err(Cep18Error::InvalidSymbol);
+    }
+    if decimals > MAX_DECIMALS {
+        return Err(Cep18Error::InvalidDecimals);
+    }
+    Ok(())
+}
+
+/// Validates the security lists passed at installation. As a key holds a single badge, it can
+/// only be listed once across all lists, and only account hashes and hashes are supported.
+pub(crate) fn validate_key_lists(lists: &[&Option<Vec<Key>>]) -> Result<(), Cep18Error> {
+    let mut listed = BTreeSet::new();
+    for &key in lists.iter().copied().flatten().flatten() {
+        if !matches!(key, Key::Account(_) | Key::Hash(_)) {
+            return Err(Cep18Error::UnsupportedKeyVariant);
+        }
+        if !listed.insert(key) {
+            return Err(Cep18Error::DuplicateListEntry);
+        }
+    }
+    Ok(())
+}
+
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum SecurityBadge {
     Admin = 0,
     Minter = 1,
     None = 2,
+    MasterMinter = 3,
+    KycOperator = 4,
+    Enforcer = 5,
+    Notary = 6,
 }
 
 impl CLTyped for SecurityBadge {
@@ -174,6 +250,10 @@ impl FromBytes for SecurityBadge {
                 0 => SecurityBadge::Admin,
                 1 => SecurityBadge::Minter,
                 2 => SecurityBadge::None,
+                3 => SecurityBadge::MasterMinter,
+                4 => SecurityBadge::KycOperator,
+                5 => SecurityBadge::Enforcer,
+                6 => SecurityBadge::Notary,
                 _ => return Err(bytesrepr::Error::LeftOverBytes),
             },
             &[],
@@ -181,27 +261,121 @@ impl FromBytes for SecurityBadge {
     }
 }
 
+/// Reads the security badge held by `key`, if any.
+pub(crate) fn get_sec_badge(key: Key) -> Option<SecurityBadge> {
+    dictionary_get::<SecurityBadge>(
+        get_uref(SECURITY_BADGES),
+        &base64::encode(key.to_bytes().unwrap_or_revert()),
+    )
+    .unwrap_or_revert()
+}
+
 pub fn sec_check(allowed_badge_list: Vec<SecurityBadge>) {
-    let caller = get_immediate_caller_address()
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    if !allowed_badge_list
+        .contains(&get_sec_badge(caller).unwrap_or_revert_with(Cep18Error::InsufficientRights))
+    {
+        revert(Cep18Error::InsufficientRights)
+    }
+}
+
+/// Creates a dictionary item key for the member of `badge` at `index`.
+fn make_role_member_item_key(badge: SecurityBadge, index: u32) -> String {
+    format!("{}_{}", badge as u8, index)
+}
+
+/// Creates a dictionary item key for a member.
+fn make_member_item_key(member: Key) -> String {
+    base64::encode(member.to_bytes().unwrap_or_revert())
+}
+
+/// Reads the number of keys holding `badge`.
+pub(crate) fn read_role_member_count(badge: SecurityBadge) -> u32 {
+    dictionary_get(get_uref(ROLE_MEMBERS), &(badge as u8).to_string())
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Writes the number of keys holding `badge`.
+fn write_role_member_count(badge: SecurityBadge, count: u32) {
+    dictionary_put(get_uref(ROLE_MEMBERS), &(badge as u8).to_string(), count)
+}
+
+/// Reads up to `count` keys holding `badge`, skipping the first `start` ones.
+pub(crate) fn read_role_members(badge: SecurityBadge, start: u32, count: u32) -> Vec<Key> {
+    let role_members_uref = get_uref(ROLE_MEMBERS);
+    let end = read_role_member_count(badge).min(start.saturating_add(count));
+    (start..end)
+        .map(|index| {
+            dictionary_get(role_members_uref, &make_role_member_item_key(badge, index))
+                .unwrap_or_revert()
+                .unwrap_or_revert()
+        })
+        .collect()
+}
+
+/// Appends `member` to the keys holding `badge`.
+fn add_role_member(badge: SecurityBadge, member: Key) {
+    let index = read_role_member_count(badge);
+    dictionary_put(
+        get_uref(ROLE_MEMBERS),
+        &make_role_member_item_key(badge, index),
+        member,
+    );
+    dictionary_put(
+        get_uref(ROLE_MEMBER_INDICES),
+        &make_member_item_key(member),
+        index,
+    );
+    write_role_member_count(badge, index + 1);
+}
+
+/// Removes `member` from the keys holding `badge`, moving the last of them to its index.
+fn remove_role_member(badge: SecurityBadge, member: Key) {
+    let role_members_uref = get_uref(ROLE_MEMBERS);
+    let role_member_indices_uref = get_uref(ROLE_MEMBER_INDICES);
+    let index: u32 = dictionary_get(role_member_indices_uref, &make_member_item_key(member))
         .unwrap_or_revert()
-        .to_bytes()
         .unwrap_or_revert();
-    if !allowed_badge_list.contains(
-        &dictionary_get::<SecurityBadge>(get_uref(SECURITY_BADGES), &base64::encode(caller))
-            .unwrap_or_revert()
-            .unwrap_or_revert_with(Cep18Error::InsufficientRights),
-    ) {
-        revert(Cep18Error::InsufficientRights)
+    let last_index = read_role_member_count(badge) - 1;
+    if index != last_index {
+        let last_member: Key = dictionary_get(
+            role_members_uref,
+            &make_role_member_item_key(badge, last_index),
+        )
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+        dictionary_put(
+            role_members_uref,
+            &make_role_member_item_key(badge, index),
+            last_member,
+        );
+        dictionary_put(
+            role_member_indices_uref,
+            &make_member_item_key(last_member),
+            index,
+        );
     }
+    write_role_member_count(badge, last_index);
 }
 
+/// Grants the badges of `badge_map`, moving each key from the members of its previous badge to
+/// the members of its new one. Keys given `SecurityBadge::None` are not listed.
 pub fn change_sec_badge(badge_map: &BTreeMap<Key, SecurityBadge>) {
     let sec_uref = get_uref(SECURITY_BADGES);
     for (&user, &badge) in badge_map {
-        dictionary_put(
-            sec_uref,
-            &base64::encode(user.to_bytes().unwrap_or_revert()),
-            badge,
-        )
+        let previous_badge = get_sec_badge(user);
+        if previous_badge == Some(badge) {
+            continue;
+        }
+        if let Some(previous_badge) =
+            previous_badge.filter(|previous_badge| *previous_badge != SecurityBadge::None)
+        {
+            remove_role_member(previous_badge, user);
+        }
+        if badge != SecurityBadge::None {
+            add_role_member(badge, user);
+        }
+        dictionary_put(sec_uref, &make_member_item_key(user), badge)
     }
 }
@@ -0,0 +1,150 @@
+//! Implementation of the lockup schedules of minted allocations.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key, URef, U256,
+};
+
+use crate::{constants::LOCKS, error::Cep18Error, utils};
+
+/// An amount released linearly over `duration` milliseconds from `start`, nothing being released
+/// before `start + cliff`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Lock {
+    pub amount: U256,
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+}
+
+impl Lock {
+    /// Returns the part of the amount released at block time `now`.
+    fn released_at(&self, now: u64) -> U256 {
+        if now < self.start.saturating_add(self.cliff) {
+            return U256::zero();
+        }
+        let elapsed = now - self.start;
+        if elapsed >= self.duration {
+            return self.amount;
+        }
+        // Split the product to avoid overflowing with large amounts.
+        let duration = U256::from(self.duration);
+        let elapsed = U256::from(elapsed);
+        self.amount / duration * elapsed + self.amount % duration * elapsed / duration
+    }
+
+    /// Returns the part of the amount still locked at block time `now`.
+    fn locked_at(&self, now: u64) -> U256 {
+        self.amount - self.released_at(now)
+    }
+}
+
+impl CLTyped for Lock {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Lock {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.start.to_bytes()?);
+        result.append(&mut self.cliff.to_bytes()?);
+        result.append(&mut self.duration.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.amount.serialized_length()
+            + self.start.serialized_length()
+            + self.cliff.serialized_length()
+            + self.duration.serialized_length()
+    }
+}
+
+impl FromBytes for Lock {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (amount, remainder) = U256::from_bytes(bytes)?;
+        let (start, remainder) = u64::from_bytes(remainder)?;
+        let (cliff, remainder) = u64::from_bytes(remainder)?;
+        let (duration, remainder) = u64::from_bytes(remainder)?;
+        Ok((
+            Lock {
+                amount,
+                start,
+                cliff,
+                duration,
+            },
+            remainder,
+        ))
+    }
+}
+
+/// Creates a dictionary item key for an account.
+#[inline]
+fn make_dictionary_item_key(owner: Key) -> String {
+    base64::encode(owner.to_bytes().unwrap_or_revert())
+}
+
+/// Getter for the "locks" dictionary URef.
+#[inline]
+pub(crate) fn get_locks_uref() -> URef {
+    utils::get_uref(LOCKS)
+}
+
+/// Reads the locks of an account.
+pub(crate) fn read_locks_from(locks_uref: URef, owner: Key) -> Vec<Lock> {
+    storage::dictionary_get(locks_uref, &make_dictionary_item_key(owner))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Writes the locks of an account.
+pub(crate) fn write_locks_to(locks_uref: URef, owner: Key, locks: Vec<Lock>) {
+    storage::dictionary_put(locks_uref, &make_dictionary_item_key(owner), locks)
+}
+
+/// Adds a lock to an account, dropping its fully released locks.
+pub(crate) fn add_lock(owner: Key, lock: Lock) -> Result<(), Cep18Error> {
+    if lock.cliff > lock.duration {
+        return Err(Cep18Error::InvalidVestingSchedule);
+    }
+    let now = u64::from(runtime::get_blocktime());
+    let locks_uref = get_locks_uref();
+    let mut locks = read_locks_from(locks_uref, owner);
+    locks.retain(|lock| !lock.locked_at(now).is_zero());
+    locks.push(lock);
+    write_locks_to(locks_uref, owner, locks);
+    Ok(())
+}
+
+/// Returns the amount of the balance of `owner` which cannot be moved yet.
+pub(crate) fn locked_balance_of(owner: Key) -> U256 {
+    let now = u64::from(runtime::get_blocktime());
+    read_locks_from(get_locks_uref(), owner)
+        .iter()
+        .fold(U256::zero(), |locked, lock| {
+            locked.saturating_add(lock.locked_at(now))
+        })
+}
+
+/// Moves the pending locks of `from` to `to`, along with a recovered balance.
+pub(crate) fn move_locks(from: Key, to: Key) {
+    let now = u64::from(runtime::get_blocktime());
+    let locks_uref = get_locks_uref();
+    let mut moved_locks = read_locks_from(locks_uref, from);
+    if moved_locks.is_empty() {
+        return;
+    }
+    let mut locks = read_locks_from(locks_uref, to);
+    locks.append(&mut moved_locks);
+    locks.retain(|lock| !lock.locked_at(now).is_zero());
+    write_locks_to(locks_uref, to, locks);
+    write_locks_to(locks_uref, from, Vec::new());
+}
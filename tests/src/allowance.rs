@@ -5,10 +5,11 @@ use crate::utility::{
     constants::{
         ACCOUNT_1_ADDR, ALLOWANCE_AMOUNT_1, ALLOWANCE_AMOUNT_2, ARG_AMOUNT, ARG_OWNER,
         ARG_RECIPIENT, ARG_SPENDER, DECREASE_ALLOWANCE, ERROR_INSUFFICIENT_ALLOWANCE,
-        INCREASE_ALLOWANCE, METHOD_APPROVE, METHOD_TRANSFER_FROM,
+        INCREASE_ALLOWANCE, METHOD_APPROVE, METHOD_TRANSFER_FROM, TOKEN_TOTAL_SUPPLY,
     },
     installer_request_builders::{
-        cep18_check_allowance_of, make_cep18_approve_request, setup, test_approve_for, TestContext,
+        cep18_check_allowance_of, cep18_check_allowances_of, cep18_check_balances_of,
+        make_cep18_approve_request, setup, test_approve_for, TestContext,
     },
 };
 use casper_execution_engine::core::{
@@ -197,3 +198,27 @@ fn test_decrease_allowance() {
         (allowance_amount_1 * 2) - allowance_amount_2
     );
 }
+
+#[test]
+fn should_read_balances_and_allowances_in_batch() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+
+    let approve_request =
+        make_cep18_approve_request(owner, &cep18_token, spender, U256::from(ALLOWANCE_AMOUNT_1));
+    builder.exec(approve_request).expect_success().commit();
+
+    let balances = cep18_check_balances_of(&mut builder, &cep18_token, vec![owner, spender]);
+    assert_eq!(balances, vec![U256::from(TOKEN_TOTAL_SUPPLY), U256::zero()]);
+
+    let allowances = cep18_check_allowances_of(
+        &mut builder,
+        &cep18_token,
+        vec![(owner, spender), (spender, owner)],
+    );
+    assert_eq!(
+        allowances,
+        vec![U256::from(ALLOWANCE_AMOUNT_1), U256::zero()]
+    );
+}
@@ -0,0 +1,335 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ACCOUNT, ARG_ACCOUNTS, ARG_AMOUNT, ARG_DECIMALS,
+        ARG_EXPIRY, ARG_NAME, ARG_RECIPIENT, ARG_SYMBOL, ARG_TOTAL_SUPPLY, ARG_TRANSFER_ID,
+        ERROR_CLAIM_NOT_EXPIRED, ERROR_INSUFFICIENT_RIGHTS, ERROR_INVALID_CLAIM_AMOUNT,
+        ERROR_RECIPIENT_FROZEN, ERROR_RECIPIENT_NOT_WHITELISTED, KYC_OPERATOR_LIST,
+        METHOD_ADD_TO_WHITELIST, METHOD_CLAIM, METHOD_FREEZE, METHOD_RECLAIM,
+        METHOD_REMOVE_FROM_WHITELIST, METHOD_SEND_CLAIMABLE, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1, TRANSFER_MODE,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, make_cep18_send_claimable_request, setup, setup_with_args,
+        TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_only_let_the_recipient_claim() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let send_claimable_request = make_cep18_send_claimable_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(TRANSFER_AMOUNT_1),
+        10_000,
+    );
+    builder
+        .exec(send_claimable_request)
+        .expect_success()
+        .commit();
+
+    let claim_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_CLAIM,
+        runtime_args! {
+            ARG_TRANSFER_ID => 0u64,
+        },
+    )
+    .build();
+    builder.exec(claim_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+
+    let claim_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_CLAIM,
+        runtime_args! {
+            ARG_TRANSFER_ID => 0u64,
+        },
+    )
+    .build();
+    builder.exec(claim_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        U256::from(TRANSFER_AMOUNT_1)
+    );
+}
+
+#[test]
+fn should_only_reclaim_after_expiry() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let send_claimable_request = make_cep18_send_claimable_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(TRANSFER_AMOUNT_1),
+        10_000,
+    );
+    builder
+        .exec(send_claimable_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(
+            &mut builder,
+            &cep18_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(TOKEN_TOTAL_SUPPLY - TRANSFER_AMOUNT_1)
+    );
+
+    let reclaim_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_RECLAIM,
+        runtime_args! {
+            ARG_TRANSFER_ID => 0u64,
+        },
+    )
+    .with_block_time(5_000)
+    .build();
+    builder.exec(reclaim_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_CLAIM_NOT_EXPIRED),
+        "{:?}",
+        error
+    );
+
+    let reclaim_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_RECLAIM,
+        runtime_args! {
+            ARG_TRANSFER_ID => 0u64,
+        },
+    )
+    .with_block_time(10_000)
+    .build();
+    builder.exec(reclaim_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(
+            &mut builder,
+            &cep18_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+}
+
+#[test]
+fn should_not_send_zero_claimable() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let send_claimable_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SEND_CLAIMABLE,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::zero(),
+            ARG_EXPIRY => 10_000u64,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(send_claimable_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_CLAIM_AMOUNT),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_let_the_sender_claim_nor_the_recipient_reclaim() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let send_claimable_request = make_cep18_send_claimable_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(TRANSFER_AMOUNT_1),
+        10_000,
+    );
+    builder
+        .exec(send_claimable_request)
+        .expect_success()
+        .commit();
+
+    let claim_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CLAIM,
+        runtime_args! {
+            ARG_TRANSFER_ID => 0u64,
+        },
+    )
+    .build();
+    builder.exec(claim_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+
+    let reclaim_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_RECLAIM,
+        runtime_args! {
+            ARG_TRANSFER_ID => 0u64,
+        },
+    )
+    .with_block_time(10_000)
+    .build();
+    builder.exec(reclaim_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_claim_to_frozen_recipient() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let send_claimable_request = make_cep18_send_claimable_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(TRANSFER_AMOUNT_1),
+        10_000,
+    );
+    builder
+        .exec(send_claimable_request)
+        .expect_success()
+        .commit();
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FREEZE,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+        },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
+
+    let claim_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_CLAIM,
+        runtime_args! {
+            ARG_TRANSFER_ID => 0u64,
+        },
+    )
+    .build();
+    builder.exec(claim_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECIPIENT_FROZEN),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_claim_to_recipient_removed_from_whitelist() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        TRANSFER_MODE => 1u8,
+        KYC_OPERATOR_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+    });
+
+    let add_to_whitelist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_ADD_TO_WHITELIST,
+        runtime_args! {
+            ARG_ACCOUNTS => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR), Key::Account(*ACCOUNT_1_ADDR)],
+        },
+    )
+    .build();
+    builder
+        .exec(add_to_whitelist_request)
+        .expect_success()
+        .commit();
+
+    let send_claimable_request = make_cep18_send_claimable_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(TRANSFER_AMOUNT_1),
+        10_000,
+    );
+    builder
+        .exec(send_claimable_request)
+        .expect_success()
+        .commit();
+
+    let remove_from_whitelist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_REMOVE_FROM_WHITELIST,
+        runtime_args! {
+            ARG_ACCOUNTS => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        },
+    )
+    .build();
+    builder
+        .exec(remove_from_whitelist_request)
+        .expect_success()
+        .commit();
+
+    let claim_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_CLAIM,
+        runtime_args! {
+            ARG_TRANSFER_ID => 0u64,
+        },
+    )
+    .build();
+    builder.exec(claim_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECIPIENT_NOT_WHITELISTED),
+        "{:?}",
+        error
+    );
+}
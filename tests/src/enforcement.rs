@@ -0,0 +1,140 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ACCOUNT, ARG_AMOUNT, ARG_DECIMALS, ARG_FROM, ARG_NAME,
+        ARG_REASON, ARG_SYMBOL, ARG_TO, ARG_TOTAL_SUPPLY, ENFORCEMENT_MODE, ENFORCER_LIST,
+        ERROR_ENFORCEMENT_DISABLED, ERROR_RECIPIENT_FROZEN, METHOD_CLAWBACK, METHOD_FORCE_TRANSFER,
+        METHOD_FREEZE, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+        TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, cep18_check_total_supply, setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_not_force_transfer_when_enforcement_disabled() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENFORCER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+
+    let force_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_FORCE_TRANSFER,
+        runtime_args! {
+            ARG_FROM => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TO => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+            ARG_REASON => "court order".to_string(),
+        },
+    )
+    .build();
+    builder.exec(force_transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ENFORCEMENT_DISABLED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_force_transfer_and_clawback_as_enforcer() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENFORCEMENT_MODE => 1u8,
+        ENFORCER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+    let amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let force_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_FORCE_TRANSFER,
+        runtime_args! {
+            ARG_FROM => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TO => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => amount,
+            ARG_REASON => "court order".to_string(),
+        },
+    )
+    .build();
+    builder
+        .exec(force_transfer_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        amount
+    );
+
+    let clawback_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_CLAWBACK,
+        runtime_args! {
+            ARG_FROM => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    builder.exec(clawback_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY) - amount
+    );
+
+    // Freezes are not overridden in the `Enabled` enforcement mode.
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FREEZE,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_2_ADDR),
+        },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
+
+    let force_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_FORCE_TRANSFER,
+        runtime_args! {
+            ARG_FROM => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TO => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => amount,
+            ARG_REASON => "court order".to_string(),
+        },
+    )
+    .build();
+    builder.exec(force_transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECIPIENT_FROZEN),
+        "{:?}",
+        error
+    );
+}
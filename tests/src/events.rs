@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+
+use casper_engine_test_support::{InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_DECIMALS, ARG_NAME, ARG_SYMBOL, ARG_TOTAL_SUPPLY, EVENTS_KEY,
+        EVENTS_LENGTH_KEY, EVENTS_MODE, EVENTS_SCHEMA_KEY, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{make_cep18_transfer_request, setup_with_args, TestContext},
+};
+
+/// Reads the event of a sequence number from the native event log of the token.
+fn get_native_event(
+    builder: &InMemoryWasmTestBuilder,
+    cep18_token: &ContractHash,
+    sequence: u64,
+) -> BTreeMap<String, String> {
+    let events_uref = builder
+        .get_contract(*cep18_token)
+        .expect("should have contract")
+        .named_keys()
+        .get(EVENTS_KEY)
+        .and_then(Key::as_uref)
+        .copied()
+        .expect("should have events dictionary");
+    builder
+        .query_dictionary_item(None, events_uref, &sequence.to_string())
+        .expect("should have event")
+        .as_cl_value()
+        .cloned()
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be a map of strings")
+}
+
+#[test]
+fn should_record_native_events_along_with_ces() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        EVENTS_MODE => 3u8,
+    });
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let transfer_request = make_cep18_transfer_request(
+        sender,
+        &cep18_token,
+        recipient,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    let contract = builder
+        .get_contract(cep18_token)
+        .expect("should have contract");
+    assert!(contract.named_keys().contains_key(EVENTS_SCHEMA_KEY));
+
+    let events_length: u64 = builder.get_value(cep18_token, EVENTS_LENGTH_KEY);
+    let last_event = get_native_event(&builder, &cep18_token, events_length - 1);
+
+    assert_eq!(last_event["event_type"], "Transfer");
+    assert_eq!(last_event["sequence"], (events_length - 1).to_string());
+    assert_eq!(last_event["sender"], sender.to_formatted_string());
+    assert_eq!(last_event["recipient"], recipient.to_formatted_string());
+    assert_eq!(last_event["amount"], TRANSFER_AMOUNT_1.to_string());
+}
+
+#[test]
+fn should_record_init_and_genesis_mint_events() {
+    let (builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        EVENTS_MODE => 2u8,
+    });
+
+    let events_length: u64 = builder.get_value(cep18_token, EVENTS_LENGTH_KEY);
+    assert_eq!(events_length, 2);
+
+    let init_event = get_native_event(&builder, &cep18_token, 0);
+    assert_eq!(init_event["event_type"], "Init");
+    assert_eq!(init_event["name"], TOKEN_NAME);
+    assert_eq!(init_event["symbol"], TOKEN_SYMBOL);
+    assert_eq!(init_event["events_mode"], "2");
+
+    let mint_event = get_native_event(&builder, &cep18_token, 1);
+    assert_eq!(mint_event["event_type"], "Mint");
+    assert_eq!(
+        mint_event["recipient"],
+        Key::Account(*DEFAULT_ACCOUNT_ADDR).to_formatted_string()
+    );
+    assert_eq!(mint_event["amount"], TOKEN_TOTAL_SUPPLY.to_string());
+}
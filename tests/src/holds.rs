@@ -0,0 +1,148 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_RECIPIENT,
+        ARG_SYMBOL, ARG_TOTAL_SUPPLY, ERROR_AMOUNT_ON_HOLD, ERROR_HOLD_NOT_FOUND, METHOD_TRANSFER,
+        NOTARY_LIST, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, make_cep18_execute_hold_request, make_cep18_hold_request,
+        make_cep18_transfer_request, setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+const OPERATION_ID: &str = "settlement-1";
+
+#[test]
+fn should_not_transfer_held_amount_until_executed() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        NOTARY_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+
+    let hold_request = make_cep18_hold_request(
+        *ACCOUNT_1_ADDR,
+        &cep18_token,
+        OPERATION_ID,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::Account(*ACCOUNT_2_ADDR),
+        U256::from(TOKEN_TOTAL_SUPPLY),
+        10_000,
+    );
+    builder.exec(hold_request).expect_success().commit();
+
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::one(),
+    );
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_AMOUNT_ON_HOLD),
+        "{:?}",
+        error
+    );
+
+    let execute_hold_request =
+        make_cep18_execute_hold_request(*ACCOUNT_1_ADDR, &cep18_token, OPERATION_ID, 2_000);
+    builder.exec(execute_hold_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+}
+
+#[test]
+fn should_release_hold_on_expiration() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        NOTARY_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+
+    let hold_request = make_cep18_hold_request(
+        *ACCOUNT_1_ADDR,
+        &cep18_token,
+        OPERATION_ID,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::Account(*ACCOUNT_2_ADDR),
+        U256::from(TOKEN_TOTAL_SUPPLY),
+        10_000,
+    );
+    builder.exec(hold_request).expect_success().commit();
+
+    // No call is needed to release the hold once its expiration is reached.
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(TOKEN_TOTAL_SUPPLY),
+        },
+    )
+    .with_block_time(10_000)
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_not_execute_expired_hold() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        NOTARY_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+
+    let hold_request = make_cep18_hold_request(
+        *ACCOUNT_1_ADDR,
+        &cep18_token,
+        OPERATION_ID,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::Account(*ACCOUNT_2_ADDR),
+        U256::from(TOKEN_TOTAL_SUPPLY),
+        10_000,
+    );
+    builder.exec(hold_request).expect_success().commit();
+
+    let execute_hold_request =
+        make_cep18_execute_hold_request(*ACCOUNT_1_ADDR, &cep18_token, OPERATION_ID, 10_000);
+    builder.exec(execute_hold_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_HOLD_NOT_FOUND),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::zero()
+    );
+}
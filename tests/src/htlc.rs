@@ -0,0 +1,279 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{
+    bytesrepr::Bytes, crypto::blake2b, runtime_args, ApiError, Key, RuntimeArgs, U256,
+};
+use sha2::{Digest, Sha256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_ACCOUNT, ARG_AMOUNT, ARG_HASHLOCK, ARG_LOCK_ID, ARG_PREIMAGE,
+        ARG_RECIPIENT, ARG_TIMELOCK, ERROR_HTLC_EXPIRED, ERROR_HTLC_NOT_EXPIRED,
+        ERROR_HTLC_NOT_FOUND, ERROR_INSUFFICIENT_RIGHTS, ERROR_INVALID_PREIMAGE,
+        ERROR_RECIPIENT_FROZEN, METHOD_FREEZE, METHOD_HTLC_LOCK, METHOD_HTLC_REDEEM,
+        METHOD_HTLC_REFUND, TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{cep18_check_balance_of, setup, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+const PREIMAGE: &[u8] = b"swap secret";
+
+#[test]
+fn should_redeem_htlc_with_preimage_before_timelock() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let hashlock: [u8; 32] = Sha256::digest(PREIMAGE).into();
+
+    let lock_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_HTLC_LOCK,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+            ARG_HASHLOCK => hashlock,
+            ARG_TIMELOCK => 10_000u64,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(lock_request).expect_success().commit();
+
+    let refund_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_HTLC_REFUND,
+        runtime_args! {
+            ARG_LOCK_ID => 0u64,
+        },
+    )
+    .with_block_time(2_000)
+    .build();
+    builder.exec(refund_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_HTLC_NOT_EXPIRED),
+        "{:?}",
+        error
+    );
+
+    let redeem_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_HTLC_REDEEM,
+        runtime_args! {
+            ARG_LOCK_ID => 0u64,
+            ARG_PREIMAGE => Bytes::from(b"wrong secret".to_vec()),
+        },
+    )
+    .with_block_time(2_000)
+    .build();
+    builder.exec(redeem_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_PREIMAGE),
+        "{:?}",
+        error
+    );
+
+    let redeem_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_HTLC_REDEEM,
+        runtime_args! {
+            ARG_LOCK_ID => 0u64,
+            ARG_PREIMAGE => Bytes::from(PREIMAGE.to_vec()),
+        },
+    )
+    .with_block_time(2_000)
+    .build();
+    builder.exec(redeem_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        U256::from(TRANSFER_AMOUNT_1)
+    );
+}
+
+#[test]
+fn should_redeem_blake2b_htlc_only_once() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let lock_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_HTLC_LOCK,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+            ARG_HASHLOCK => blake2b(PREIMAGE),
+            ARG_TIMELOCK => 10_000u64,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(lock_request).expect_success().commit();
+
+    for _ in 0..2 {
+        let redeem_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_1_ADDR,
+            cep18_token,
+            METHOD_HTLC_REDEEM,
+            runtime_args! {
+                ARG_LOCK_ID => 0u64,
+                ARG_PREIMAGE => Bytes::from(PREIMAGE.to_vec()),
+            },
+        )
+        .with_block_time(2_000)
+        .build();
+        builder.exec(redeem_request).commit();
+    }
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_HTLC_NOT_FOUND),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        U256::from(TRANSFER_AMOUNT_1)
+    );
+}
+
+#[test]
+fn should_only_refund_expired_htlc_to_its_sender() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let hashlock: [u8; 32] = Sha256::digest(PREIMAGE).into();
+
+    let lock_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_HTLC_LOCK,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+            ARG_HASHLOCK => hashlock,
+            ARG_TIMELOCK => 10_000u64,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(lock_request).expect_success().commit();
+
+    let redeem_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_HTLC_REDEEM,
+        runtime_args! {
+            ARG_LOCK_ID => 0u64,
+            ARG_PREIMAGE => Bytes::from(PREIMAGE.to_vec()),
+        },
+    )
+    .with_block_time(10_000)
+    .build();
+    builder.exec(redeem_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_HTLC_EXPIRED),
+        "{:?}",
+        error
+    );
+
+    let refund_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_HTLC_REFUND,
+        runtime_args! {
+            ARG_LOCK_ID => 0u64,
+        },
+    )
+    .with_block_time(10_000)
+    .build();
+    builder.exec(refund_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+
+    let refund_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_HTLC_REFUND,
+        runtime_args! {
+            ARG_LOCK_ID => 0u64,
+        },
+    )
+    .with_block_time(10_000)
+    .build();
+    builder.exec(refund_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(
+            &mut builder,
+            &cep18_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+}
+
+#[test]
+fn should_not_redeem_htlc_to_frozen_recipient() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let hashlock: [u8; 32] = Sha256::digest(PREIMAGE).into();
+
+    let lock_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_HTLC_LOCK,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+            ARG_HASHLOCK => hashlock,
+            ARG_TIMELOCK => 10_000u64,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(lock_request).expect_success().commit();
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FREEZE,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+        },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
+
+    let redeem_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_HTLC_REDEEM,
+        runtime_args! {
+            ARG_LOCK_ID => 0u64,
+            ARG_PREIMAGE => Bytes::from(PREIMAGE.to_vec()),
+        },
+    )
+    .with_block_time(2_000)
+    .build();
+    builder.exec(redeem_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECIPIENT_FROZEN),
+        "{:?}",
+        error
+    );
+}
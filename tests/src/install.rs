@@ -1,16 +1,29 @@
-use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
-use casper_types::{Key, U256};
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{account::AccountHash, runtime_args, ApiError, Key, RuntimeArgs, U256};
 
 use crate::utility::{
     constants::{
-        ALLOWANCES_KEY, BALANCES_KEY, DECIMALS_KEY, NAME_KEY, SYMBOL_KEY, TOKEN_DECIMALS,
-        TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY, TOTAL_SUPPLY_KEY,
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ADMIN_LIST, ALLOWANCES_KEY, ARG_ACCOUNTS,
+        ARG_CHUNKED_DISTRIBUTION, ARG_DECIMALS, ARG_INITIAL_BALANCES, ARG_NAME, ARG_SYMBOL,
+        ARG_TOKEN_CONTRACT, ARG_TOTAL_SUPPLY, BALANCES_KEY, CEP18_CONTRACT_WASM,
+        CHECK_CONTRACT_VERSION_ENTRYPOINT, CHECK_SUPPORTED_INTERFACES_ENTRYPOINT, DECIMALS_KEY,
+        ENABLE_MINT_BURN, ERROR_DUPLICATE_LIST_ENTRY, ERROR_INITIAL_DISTRIBUTION_PENDING,
+        ERROR_INVALID_DECIMALS, ERROR_INVALID_INITIAL_BALANCES, ERROR_NAMED_KEY_ALREADY_EXISTS,
+        ERROR_RECIPIENT_NOT_WHITELISTED, EVENTS_MODE, KYC_OPERATOR_LIST, METHOD_ADD_TO_WHITELIST,
+        METHOD_INIT_DISTRIBUTE, METHOD_SET_MODALITIES, MINTER_LIST, NAME_KEY, SCHEMA_VERSION_KEY,
+        SYMBOL_KEY, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY, TOTAL_SUPPLY_KEY,
+        TRANSFER_AMOUNT_1, TRANSFER_MODE,
     },
     installer_request_builders::{
-        cep18_check_balance_of, invert_cep18_address, setup, TestContext,
+        cep18_check_balance_of, get_test_result, invert_cep18_address, make_cep18_transfer_request,
+        setup, setup_with_args, TestContext,
     },
 };
 
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
 #[test]
 fn should_have_queryable_properties() {
     let (mut builder, TestContext { cep18_token, .. }) = setup();
@@ -44,6 +57,92 @@ fn should_have_queryable_properties() {
     assert_eq!(inverted_owner_balance, U256::zero());
 }
 
+#[test]
+fn should_expose_interface_discovery() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+
+    let schema_version: u32 = builder.get_value(cep18_token, SCHEMA_VERSION_KEY);
+    assert_eq!(schema_version, 1);
+
+    let check_contract_version_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_CONTRACT_VERSION_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(cep18_token),
+        },
+    )
+    .build();
+    builder
+        .exec(check_contract_version_request)
+        .expect_success()
+        .commit();
+    let contract_version: (String, u32) =
+        get_test_result(&mut builder, cep18_test_contract_package);
+    assert_eq!(contract_version, ("1.0.3".to_string(), 1));
+
+    let check_supported_interfaces_request = || {
+        ExecuteRequestBuilder::versioned_contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_test_contract_package,
+            None,
+            CHECK_SUPPORTED_INTERFACES_ENTRYPOINT,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => Key::from(cep18_token),
+            },
+        )
+        .build()
+    };
+
+    builder
+        .exec(check_supported_interfaces_request())
+        .expect_success()
+        .commit();
+    let interfaces: Vec<String> = get_test_result(&mut builder, cep18_test_contract_package);
+    assert!(interfaces.contains(&"cep18".to_string()));
+    assert!(!interfaces.contains(&"cep18-mintable".to_string()));
+    assert!(!interfaces.contains(&"cep18-soulbound".to_string()));
+    for interface_id in [
+        "cep18-freezable",
+        "erc1404",
+        "cep18-role-queries",
+        "cep18-try-transfers",
+        "cep18-batch-queries",
+    ] {
+        assert!(interfaces.contains(&interface_id.to_string()));
+    }
+
+    let set_modalities_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_MODALITIES,
+        runtime_args! {
+            EVENTS_MODE => 0u8,
+            ENABLE_MINT_BURN => 1u8,
+        },
+    )
+    .build();
+    builder
+        .exec(set_modalities_request)
+        .expect_success()
+        .commit();
+
+    builder
+        .exec(check_supported_interfaces_request())
+        .expect_success()
+        .commit();
+    let interfaces: Vec<String> = get_test_result(&mut builder, cep18_test_contract_package);
+    assert!(interfaces.contains(&"cep18".to_string()));
+    assert!(interfaces.contains(&"cep18-mintable".to_string()));
+}
+
 #[test]
 fn should_not_store_balances_or_allowances_under_account_after_install() {
     let (builder, _contract_hash) = setup();
@@ -56,3 +155,241 @@ fn should_not_store_balances_or_allowances_under_account_after_install() {
     assert!(!named_keys.contains_key(BALANCES_KEY), "{:?}", named_keys);
     assert!(!named_keys.contains_key(ALLOWANCES_KEY), "{:?}", named_keys);
 }
+
+#[test]
+fn should_distribute_initial_balances_in_chunks() {
+    let half_supply = U256::from(TOKEN_TOTAL_SUPPLY / 2);
+    let account_1_key = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2_key = Key::Account(*ACCOUNT_2_ADDR);
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_INITIAL_BALANCES => vec![(account_1_key, half_supply)],
+        ARG_CHUNKED_DISTRIBUTION => true,
+    });
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, account_1_key),
+        half_supply
+    );
+    assert_eq!(
+        cep18_check_balance_of(
+            &mut builder,
+            &cep18_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::zero()
+    );
+
+    let transfer_request = make_cep18_transfer_request(
+        account_1_key,
+        &cep18_token,
+        account_2_key,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INITIAL_DISTRIBUTION_PENDING),
+        "{:?}",
+        error
+    );
+
+    let init_distribute_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_INIT_DISTRIBUTE,
+        runtime_args! {
+            ARG_INITIAL_BALANCES => vec![(account_2_key, half_supply)],
+        },
+    )
+    .build();
+    builder
+        .exec(init_distribute_request)
+        .expect_success()
+        .commit();
+
+    let transfer_request = make_cep18_transfer_request(
+        account_1_key,
+        &cep18_token,
+        account_2_key,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, account_2_key),
+        half_supply + U256::from(TRANSFER_AMOUNT_1)
+    );
+}
+
+#[test]
+fn should_not_install_with_initial_balances_not_summing_to_total_supply() {
+    let (mut builder, _) = setup();
+    let half_supply = U256::from(TOKEN_TOTAL_SUPPLY / 2);
+
+    let invalid_initial_balances = [
+        // Short of the total supply without further chunks announced.
+        (vec![(Key::Account(*ACCOUNT_1_ADDR), half_supply)], false),
+        // Above the total supply, even when chunked.
+        (
+            vec![
+                (Key::Account(*ACCOUNT_1_ADDR), half_supply),
+                (Key::Account(*ACCOUNT_2_ADDR), half_supply + 1),
+            ],
+            true,
+        ),
+        // Crediting the zero account.
+        (
+            vec![(Key::Account(AccountHash::default()), half_supply)],
+            true,
+        ),
+    ];
+
+    for (initial_balances, chunked_distribution) in invalid_initial_balances {
+        let install_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CEP18_CONTRACT_WASM,
+            runtime_args! {
+                ARG_NAME => "OtherToken",
+                ARG_SYMBOL => TOKEN_SYMBOL,
+                ARG_DECIMALS => TOKEN_DECIMALS,
+                ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+                ARG_INITIAL_BALANCES => initial_balances,
+                ARG_CHUNKED_DISTRIBUTION => chunked_distribution,
+            },
+        )
+        .build();
+        builder.exec(install_request).expect_failure();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_INITIAL_BALANCES),
+            "{:?}",
+            error
+        );
+    }
+}
+
+#[test]
+fn should_only_distribute_chunks_to_whitelisted_recipients() {
+    let half_supply = U256::from(TOKEN_TOTAL_SUPPLY / 2);
+    let account_1_key = Key::Account(*ACCOUNT_1_ADDR);
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_INITIAL_BALANCES => vec![(Key::Account(*DEFAULT_ACCOUNT_ADDR), half_supply)],
+        ARG_CHUNKED_DISTRIBUTION => true,
+        TRANSFER_MODE => 1u8,
+        KYC_OPERATOR_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+    });
+
+    let init_distribute_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_INIT_DISTRIBUTE,
+        runtime_args! {
+            ARG_INITIAL_BALANCES => vec![(account_1_key, half_supply)],
+        },
+    )
+    .build();
+    builder.exec(init_distribute_request).expect_failure();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECIPIENT_NOT_WHITELISTED),
+        "{:?}",
+        error
+    );
+
+    let whitelist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_ADD_TO_WHITELIST,
+        runtime_args! {
+            ARG_ACCOUNTS => vec![account_1_key],
+        },
+    )
+    .build();
+    builder.exec(whitelist_request).expect_success().commit();
+
+    let init_distribute_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_INIT_DISTRIBUTE,
+        runtime_args! {
+            ARG_INITIAL_BALANCES => vec![(account_1_key, half_supply)],
+        },
+    )
+    .build();
+    builder
+        .exec(init_distribute_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, account_1_key),
+        half_supply
+    );
+}
+
+#[test]
+fn should_not_install_with_invalid_arguments() {
+    let (mut builder, _) = setup();
+
+    let invalid_installs = [
+        (
+            runtime_args! {
+                ARG_NAME => "OtherToken",
+                ARG_SYMBOL => TOKEN_SYMBOL,
+                ARG_DECIMALS => 19u8,
+                ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+            },
+            ERROR_INVALID_DECIMALS,
+        ),
+        (
+            runtime_args! {
+                ARG_NAME => "OtherToken",
+                ARG_SYMBOL => TOKEN_SYMBOL,
+                ARG_DECIMALS => TOKEN_DECIMALS,
+                ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+                ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+                MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+            },
+            ERROR_DUPLICATE_LIST_ENTRY,
+        ),
+        (
+            runtime_args! {
+                ARG_NAME => TOKEN_NAME,
+                ARG_SYMBOL => TOKEN_SYMBOL,
+                ARG_DECIMALS => TOKEN_DECIMALS,
+                ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+            },
+            ERROR_NAMED_KEY_ALREADY_EXISTS,
+        ),
+    ];
+
+    for (install_args, expected_error) in invalid_installs {
+        let install_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CEP18_CONTRACT_WASM,
+            install_args,
+        )
+        .build();
+        builder.exec(install_request).expect_failure();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected_error),
+            "{:?}",
+            error
+        );
+    }
+}
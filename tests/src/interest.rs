@@ -0,0 +1,203 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_DECIMALS, ARG_DEPOSIT, ARG_EXPIRY,
+        ARG_NAME, ARG_RATE, ARG_RECIPIENT, ARG_START, ARG_STOP, ARG_STREAM_ID, ARG_SYMBOL,
+        ARG_TOTAL_SUPPLY, ARG_TRANSFER_ID, ERROR_INTEREST_DISABLED, INTEREST_MODE, INTEREST_RATE,
+        METHOD_CLAIM, METHOD_CREATE_STREAM, METHOD_SEND_CLAIMABLE, METHOD_SET_RATE,
+        METHOD_TRANSFER, METHOD_WITHDRAW_FROM_STREAM, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL,
+        TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, cep18_check_total_supply, setup, setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_accrue_interest_on_balances_and_total_supply() {
+    // 0.1% per second doubles the index in 1000 seconds.
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        INTEREST_MODE => 1u8,
+        INTEREST_RATE => U256::from(1_000_000_000_000_000u64),
+    });
+    let transfer_amount = U256::from(200_000u64);
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => transfer_amount,
+        },
+    )
+    .with_block_time(1_000_000)
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    let accrued_supply = U256::from(TOKEN_TOTAL_SUPPLY) * 2;
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        accrued_supply
+    );
+    assert_eq!(
+        cep18_check_balance_of(
+            &mut builder,
+            &cep18_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        accrued_supply - transfer_amount
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        transfer_amount
+    );
+}
+
+#[test]
+fn should_not_set_rate_without_interest_mode() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let set_rate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_RATE,
+        runtime_args! {
+            ARG_RATE => U256::one(),
+        },
+    )
+    .build();
+    builder.exec(set_rate_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INTEREST_DISABLED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_release_whole_escrow_under_rounding_index() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        INTEREST_MODE => 1u8,
+        INTEREST_RATE => U256::from(1_000_000_000_000_000u64),
+    });
+    let custody = Key::from(
+        builder
+            .get_contract(cep18_token)
+            .expect("should have contract")
+            .contract_package_hash(),
+    );
+    let amount = U256::from(1_000_001u64);
+
+    // Freeze the index at 1.333333, which scaled amounts cannot represent exactly.
+    let set_rate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_RATE,
+        runtime_args! {
+            ARG_RATE => U256::zero(),
+        },
+    )
+    .with_block_time(333_333)
+    .build();
+    builder.exec(set_rate_request).expect_success().commit();
+
+    let send_claimable_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SEND_CLAIMABLE,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => amount,
+            ARG_EXPIRY => 1_000_000u64,
+        },
+    )
+    .with_block_time(400_000)
+    .build();
+    builder
+        .exec(send_claimable_request)
+        .expect_success()
+        .commit();
+
+    let create_stream_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CREATE_STREAM,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_DEPOSIT => amount,
+            ARG_START => 400_000u64,
+            ARG_STOP => 400_003u64,
+        },
+    )
+    .with_block_time(400_000)
+    .build();
+    builder
+        .exec(create_stream_request)
+        .expect_success()
+        .commit();
+
+    let claim_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_CLAIM,
+        runtime_args! {
+            ARG_TRANSFER_ID => 0u64,
+        },
+    )
+    .with_block_time(400_000)
+    .build();
+    builder.exec(claim_request).expect_success().commit();
+
+    // Each partial withdrawal rounds, yet the last one still finds the rest of the deposit.
+    for (block_time, withdrawn) in [
+        (400_001u64, 333_333u64),
+        (400_002, 333_334),
+        (400_003, 333_334),
+    ] {
+        let withdraw_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_2_ADDR,
+            cep18_token,
+            METHOD_WITHDRAW_FROM_STREAM,
+            runtime_args! {
+                ARG_STREAM_ID => 0u64,
+                ARG_AMOUNT => U256::from(withdrawn),
+            },
+        )
+        .with_block_time(block_time)
+        .build();
+        builder.exec(withdraw_request).expect_success().commit();
+    }
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, custody),
+        U256::zero()
+    );
+    let claimed = cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR));
+    let streamed =
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR));
+    assert!(claimed >= amount, "{} < {}", claimed, amount);
+    assert!(streamed >= amount, "{} < {}", streamed, amount);
+    let remaining = cep18_check_balance_of(
+        &mut builder,
+        &cep18_token,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+    );
+    assert!(remaining + claimed + streamed <= cep18_check_total_supply(&mut builder, &cep18_token));
+}
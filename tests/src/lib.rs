@@ -1,10 +1,36 @@
 #[cfg(test)]
 mod allowance;
 #[cfg(test)]
+mod claims;
+#[cfg(test)]
+mod enforcement;
+#[cfg(test)]
+mod events;
+#[cfg(test)]
+mod holds;
+#[cfg(test)]
+mod htlc;
+#[cfg(test)]
 mod install;
 #[cfg(test)]
+mod interest;
+#[cfg(test)]
+mod metadata;
+#[cfg(test)]
 mod mint_and_burn;
 #[cfg(test)]
+mod operators;
+#[cfg(test)]
+mod quorum;
+#[cfg(test)]
+mod recovery;
+#[cfg(test)]
+mod restrictions;
+#[cfg(test)]
+mod security;
+#[cfg(test)]
+mod streams;
+#[cfg(test)]
 mod transfer;
 #[cfg(test)]
 mod utility;
@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_DECIMALS, ARG_DESCRIPTION, ARG_ICON_URL, ARG_METADATA, ARG_NAME,
+        ARG_SYMBOL, ARG_TOTAL_SUPPLY, ARG_WEBSITE, ERROR_INSUFFICIENT_RIGHTS, METHOD_SET_METADATA,
+        TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{setup_with_args, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_install_and_update_metadata() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_DESCRIPTION => "A test token",
+    });
+
+    let description: String = builder.get_value(cep18_token, ARG_DESCRIPTION);
+    assert_eq!(description, "A test token");
+    let website: String = builder.get_value(cep18_token, ARG_WEBSITE);
+    assert_eq!(website, "");
+
+    let mut metadata = BTreeMap::new();
+    metadata.insert("twitter".to_string(), "@casper".to_string());
+    let set_metadata_args = runtime_args! {
+        ARG_DESCRIPTION => "An updated test token",
+        ARG_ICON_URL => "https://example.com/icon.png",
+        ARG_WEBSITE => "https://example.com",
+        ARG_METADATA => metadata.clone(),
+    };
+
+    let non_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_SET_METADATA,
+        set_metadata_args.clone(),
+    )
+    .build();
+    builder.exec(non_admin_request).expect_failure();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+
+    let set_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_METADATA,
+        set_metadata_args,
+    )
+    .build();
+    builder.exec(set_metadata_request).expect_success().commit();
+
+    let description: String = builder.get_value(cep18_token, ARG_DESCRIPTION);
+    assert_eq!(description, "An updated test token");
+    let icon_url: String = builder.get_value(cep18_token, ARG_ICON_URL);
+    assert_eq!(icon_url, "https://example.com/icon.png");
+    let website: String = builder.get_value(cep18_token, ARG_WEBSITE);
+    assert_eq!(website, "https://example.com");
+    let stored_metadata: BTreeMap<String, String> = builder.get_value(cep18_token, ARG_METADATA);
+    assert_eq!(stored_metadata, metadata);
+}
@@ -3,14 +3,18 @@ use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
 
 use crate::utility::{
     constants::{
-        ACCOUNT_1_ADDR, ADMIN_LIST, AMOUNT, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER,
-        ARG_SYMBOL, ARG_TOTAL_SUPPLY, CHANGE_SECURITY, ENABLE_MINT_BURN,
-        ERROR_INSUFFICIENT_BALANCE, ERROR_OVERFLOW, METHOD_BURN, METHOD_MINT, MINTER_LIST,
-        NONE_LIST, OWNER, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_OWNER_ADDRESS_1, TOKEN_OWNER_ADDRESS_2,
-        TOKEN_OWNER_AMOUNT_1, TOKEN_OWNER_AMOUNT_2, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ADMIN_LIST, AMOUNT, ARG_ALLOWANCE, ARG_AMOUNT,
+        ARG_DECIMALS, ARG_EPOCH_DURATION, ARG_EPOCH_LIMIT, ARG_MINTER, ARG_NAME, ARG_OWNER,
+        ARG_SYMBOL, ARG_TOTAL_SUPPLY, CHANGE_SECURITY, CONFIGURE_MINTER, ENABLE_MINT_BURN,
+        ERROR_INSUFFICIENT_BALANCE, ERROR_MINTER_ALLOWANCE_EXCEEDED,
+        ERROR_MINT_RATE_LIMIT_EXCEEDED, ERROR_OVERFLOW, EVENTS_MODE, EVENTS_SCHEMA_KEY,
+        MASTER_MINTER_LIST, METHOD_BURN, METHOD_MINT, METHOD_SET_MODALITIES, MINTER_LIST,
+        NONE_LIST, OWNER, REMOVE_MINTER, SET_MINT_RATE_LIMIT, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_OWNER_ADDRESS_1, TOKEN_OWNER_ADDRESS_2, TOKEN_OWNER_AMOUNT_1, TOKEN_OWNER_AMOUNT_2,
+        TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
     },
     installer_request_builders::{
-        cep18_check_balance_of, cep18_check_total_supply, setup_with_args, TestContext,
+        cep18_check_balance_of, cep18_check_total_supply, setup, setup_with_args, TestContext,
     },
 };
 
@@ -462,3 +466,227 @@ fn test_change_security() {
         error
     );
 }
+
+#[test]
+fn test_minter_allowance() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MASTER_MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)]
+    });
+
+    let configure_minter_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        CONFIGURE_MINTER,
+        runtime_args! {
+            ARG_MINTER => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_ALLOWANCE => U256::from(10),
+        },
+    )
+    .build();
+
+    builder
+        .exec(configure_minter_request)
+        .expect_success()
+        .commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(6),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(6)
+    );
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(5),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MINTER_ALLOWANCE_EXCEEDED),
+        "{:?}",
+        error
+    );
+
+    let remove_minter_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        REMOVE_MINTER,
+        runtime_args! {
+            ARG_MINTER => Key::Account(*ACCOUNT_2_ADDR),
+        },
+    )
+    .build();
+
+    builder
+        .exec(remove_minter_request)
+        .expect_success()
+        .commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == 60010),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn test_mint_rate_limit() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+
+    let set_mint_rate_limit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        SET_MINT_RATE_LIMIT,
+        runtime_args! {
+            ARG_EPOCH_DURATION => 1_000u64,
+            ARG_EPOCH_LIMIT => U256::from(10),
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_mint_rate_limit_request)
+        .expect_success()
+        .commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(6),
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(5),
+        },
+    )
+    .with_block_time(1_500)
+    .build();
+
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MINT_RATE_LIMIT_EXCEEDED),
+        "{:?}",
+        error
+    );
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(5),
+        },
+    )
+    .with_block_time(2_000)
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(11)
+    );
+}
+
+#[test]
+fn should_enable_mint_and_events_through_set_modalities() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let set_modalities_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_MODALITIES,
+        runtime_args! {
+            EVENTS_MODE => 1u8,
+            ENABLE_MINT_BURN => 1u8,
+        },
+    )
+    .build();
+    builder
+        .exec(set_modalities_request)
+        .expect_success()
+        .commit();
+
+    let contract = builder
+        .get_contract(cep18_token)
+        .expect("should have contract");
+    assert!(contract.named_keys().contains_key(EVENTS_SCHEMA_KEY));
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY) + U256::one()
+    );
+}
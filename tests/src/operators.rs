@@ -0,0 +1,147 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{bytesrepr::Bytes, runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_DATA, ARG_DECIMALS, ARG_EXPIRY, ARG_HOLDER,
+        ARG_NAME, ARG_OPERATOR, ARG_RECIPIENT, ARG_SYMBOL, ARG_TOTAL_SUPPLY, DEFAULT_OPERATORS,
+        ERROR_CUSTODY_KEY_RESTRICTED, ERROR_NOT_OPERATOR_FOR_HOLDER, METHOD_OPERATOR_SEND,
+        METHOD_REVOKE_OPERATOR, METHOD_SEND_CLAIMABLE, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL,
+        TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{cep18_check_balance_of, setup_with_args, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_send_as_default_operator_until_revoked() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        DEFAULT_OPERATORS => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+    let amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let operator_send_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_OPERATOR_SEND,
+        runtime_args! {
+            ARG_HOLDER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => amount,
+            ARG_DATA => Bytes::new(),
+        },
+    )
+    .build();
+    builder
+        .exec(operator_send_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        amount
+    );
+
+    let revoke_operator_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_REVOKE_OPERATOR,
+        runtime_args! {
+            ARG_OPERATOR => Key::Account(*ACCOUNT_1_ADDR),
+        },
+    )
+    .build();
+    builder
+        .exec(revoke_operator_request)
+        .expect_success()
+        .commit();
+
+    let operator_send_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_OPERATOR_SEND,
+        runtime_args! {
+            ARG_HOLDER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => amount,
+            ARG_DATA => Bytes::new(),
+        },
+    )
+    .build();
+    builder.exec(operator_send_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_NOT_OPERATOR_FOR_HOLDER),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_send_from_custody_as_default_operator() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        DEFAULT_OPERATORS => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+    let amount = U256::from(TRANSFER_AMOUNT_1);
+    let custody = Key::from(
+        builder
+            .get_contract(cep18_token)
+            .expect("should have contract")
+            .contract_package_hash(),
+    );
+
+    let send_claimable_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SEND_CLAIMABLE,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => amount,
+            ARG_EXPIRY => 10_000u64,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder
+        .exec(send_claimable_request)
+        .expect_success()
+        .commit();
+
+    let operator_send_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_OPERATOR_SEND,
+        runtime_args! {
+            ARG_HOLDER => custody,
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => amount,
+            ARG_DATA => Bytes::new(),
+        },
+    )
+    .build();
+    builder.exec(operator_send_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_CUSTODY_KEY_RESTRICTED),
+        "{:?}",
+        error
+    );
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, custody),
+        amount
+    );
+}
@@ -0,0 +1,329 @@
+use std::collections::BTreeMap;
+
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{
+    bytesrepr::ToBytes, crypto::blake2b, runtime_args, ApiError, Key, RuntimeArgs, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ACTION_HASH, ADMIN_LIST, ADMIN_QUORUM, APPROVE_ACTION,
+        ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER, ARG_SYMBOL, ARG_TOTAL_SUPPLY,
+        CHANGE_SECURITY, ENABLE_MINT_BURN, ERROR_ACTION_ALREADY_APPROVED,
+        ERROR_INSUFFICIENT_RIGHTS, ERROR_QUORUM_EXCEEDS_ADMINS, ERROR_QUORUM_NOT_REACHED,
+        EVENTS_MODE, METHOD_MINT, METHOD_SET_MODALITIES, MINT_QUORUM_THRESHOLD, NONE_LIST,
+        SET_QUORUM, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_OWNER_ADDRESS_1, TOKEN_SYMBOL,
+        TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{cep18_check_balance_of, setup_with_args, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+fn make_mint_action_hash(owner: Key, amount: U256) -> [u8; 32] {
+    let mut preimage = METHOD_MINT.as_bytes().to_vec();
+    preimage.append(&mut owner.to_bytes().unwrap());
+    preimage.append(&mut amount.to_bytes().unwrap());
+    blake2b(preimage)
+}
+
+#[test]
+fn should_require_quorum_to_mint_above_threshold() {
+    let mint_amount = U256::from(1_000);
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        ADMIN_QUORUM => 2u8,
+        MINT_QUORUM_THRESHOLD => U256::from(100),
+    });
+
+    let small_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(100),
+        },
+    )
+    .build();
+    builder.exec(small_mint_request).expect_success().commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => mint_amount,
+        },
+    )
+    .build();
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_QUORUM_NOT_REACHED),
+        "{:?}",
+        error
+    );
+
+    let approve_action_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        APPROVE_ACTION,
+        runtime_args! {
+            ACTION_HASH => make_mint_action_hash(TOKEN_OWNER_ADDRESS_1, mint_amount),
+        },
+    )
+    .build();
+    builder
+        .exec(approve_action_request)
+        .expect_success()
+        .commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => mint_amount,
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(100) + mint_amount
+    );
+
+    // Approvals are consumed by the execution of the action.
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => mint_amount,
+        },
+    )
+    .build();
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_QUORUM_NOT_REACHED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_only_let_admins_approve_actions_once() {
+    let action_hash = make_mint_action_hash(TOKEN_OWNER_ADDRESS_1, U256::one());
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        ADMIN_QUORUM => 2u8,
+    });
+
+    let approve_action_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        APPROVE_ACTION,
+        runtime_args! {
+            ACTION_HASH => action_hash,
+        },
+    )
+    .build();
+    builder.exec(approve_action_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+
+    let approve_action_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        APPROVE_ACTION,
+        runtime_args! {
+            ACTION_HASH => action_hash,
+        },
+    )
+    .build();
+    builder
+        .exec(approve_action_request)
+        .expect_success()
+        .commit();
+
+    let approve_action_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        APPROVE_ACTION,
+        runtime_args! {
+            ACTION_HASH => action_hash,
+        },
+    )
+    .build();
+    builder.exec(approve_action_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACTION_ALREADY_APPROVED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_let_quorum_exceed_admin_count() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        ADMIN_QUORUM => 2u8,
+    });
+
+    let set_quorum_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        SET_QUORUM,
+        runtime_args! {
+            ADMIN_QUORUM => 3u8,
+            MINT_QUORUM_THRESHOLD => U256::MAX,
+        },
+    )
+    .build();
+    builder.exec(set_quorum_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_QUORUM_EXCEEDS_ADMINS),
+        "{:?}",
+        error
+    );
+
+    // Removing an admin would leave a single admin for a quorum of two, even once approved.
+    let mut badge_map: BTreeMap<Key, u8> = BTreeMap::new();
+    badge_map.insert(Key::Account(*ACCOUNT_1_ADDR), 2u8);
+    let mut preimage = CHANGE_SECURITY.as_bytes().to_vec();
+    preimage.append(&mut badge_map.to_bytes().unwrap());
+
+    let approve_action_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        APPROVE_ACTION,
+        runtime_args! {
+            ACTION_HASH => blake2b(preimage),
+        },
+    )
+    .build();
+    builder
+        .exec(approve_action_request)
+        .expect_success()
+        .commit();
+
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {
+            NONE_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        },
+    )
+    .build();
+    builder.exec(change_security_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_QUORUM_EXCEEDS_ADMINS),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_require_quorum_to_change_modalities() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        ADMIN_QUORUM => 2u8,
+    });
+
+    // Disabling mint and burn would also disable `change_security`, so a single admin must not be
+    // able to do it on its own.
+    let set_modalities_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_MODALITIES,
+        runtime_args! {
+            EVENTS_MODE => 0u8,
+            ENABLE_MINT_BURN => 0u8,
+        },
+    )
+    .build();
+    builder.exec(set_modalities_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_QUORUM_NOT_REACHED),
+        "{:?}",
+        error
+    );
+
+    let mut preimage = METHOD_SET_MODALITIES.as_bytes().to_vec();
+    preimage.append(&mut vec![0u8, 0u8]);
+
+    let approve_action_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        APPROVE_ACTION,
+        runtime_args! {
+            ACTION_HASH => blake2b(preimage),
+        },
+    )
+    .build();
+    builder
+        .exec(approve_action_request)
+        .expect_success()
+        .commit();
+
+    let set_modalities_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_MODALITIES,
+        runtime_args! {
+            EVENTS_MODE => 0u8,
+            ENABLE_MINT_BURN => 0u8,
+        },
+    )
+    .build();
+    builder
+        .exec(set_modalities_request)
+        .expect_success()
+        .commit();
+}
@@ -0,0 +1,311 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{
+    bytesrepr::ToBytes, crypto::blake2b, runtime_args, ApiError, Key, RuntimeArgs, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ACTION_HASH, ADMIN_LIST, ADMIN_QUORUM, APPROVE_ACTION,
+        ARG_AMOUNT, ARG_DECIMALS, ARG_EXPIRATION, ARG_FROM, ARG_LOST, ARG_NAME, ARG_OPERATION_ID,
+        ARG_RECIPIENT, ARG_SYMBOL, ARG_TO, ARG_TOTAL_SUPPLY, ERROR_HOLD_NOT_FOUND,
+        ERROR_NO_PENDING_RECOVERY, ERROR_QUORUM_NOT_REACHED, ERROR_RECOVERY_CHALLENGE_PERIOD,
+        METHOD_EXECUTE_HOLD, METHOD_FINALIZE_RECOVERY, METHOD_HOLD, METHOD_INITIATE_RECOVERY,
+        METHOD_TRANSFER, NOTARY_LIST, RECOVERY_DELAY, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL,
+        TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, make_cep18_initiate_recovery_request, make_cep18_transfer_request,
+        setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_finalize_recovery_after_challenge_window() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        RECOVERY_DELAY => 1_000u64,
+    });
+
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    let initiate_recovery_request = make_cep18_initiate_recovery_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        Key::Account(*ACCOUNT_2_ADDR),
+        1_000,
+    );
+    builder
+        .exec(initiate_recovery_request)
+        .expect_success()
+        .commit();
+
+    let finalize_recovery_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FINALIZE_RECOVERY,
+        runtime_args! {
+            ARG_LOST => Key::Account(*ACCOUNT_1_ADDR),
+        },
+    )
+    .with_block_time(1_999)
+    .build();
+    builder.exec(finalize_recovery_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECOVERY_CHALLENGE_PERIOD),
+        "{:?}",
+        error
+    );
+
+    let finalize_recovery_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FINALIZE_RECOVERY,
+        runtime_args! {
+            ARG_LOST => Key::Account(*ACCOUNT_1_ADDR),
+        },
+    )
+    .with_block_time(2_000)
+    .build();
+    builder
+        .exec(finalize_recovery_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::from(TRANSFER_AMOUNT_1)
+    );
+}
+
+#[test]
+fn should_abort_recovery_on_activity_of_lost_key() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        RECOVERY_DELAY => 1_000u64,
+    });
+
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    let initiate_recovery_request = make_cep18_initiate_recovery_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        Key::Account(*ACCOUNT_2_ADDR),
+        1_000,
+    );
+    builder
+        .exec(initiate_recovery_request)
+        .expect_success()
+        .commit();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .with_block_time(1_500)
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    let finalize_recovery_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FINALIZE_RECOVERY,
+        runtime_args! {
+            ARG_LOST => Key::Account(*ACCOUNT_1_ADDR),
+        },
+    )
+    .with_block_time(2_000)
+    .build();
+    builder.exec(finalize_recovery_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_NO_PENDING_RECOVERY),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_require_quorum_to_initiate_recovery() {
+    let lost = Key::Account(*ACCOUNT_1_ADDR);
+    let new_owner = Key::Account(*ACCOUNT_2_ADDR);
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+        ADMIN_QUORUM => 2u8,
+    });
+
+    let initiate_recovery_request = make_cep18_initiate_recovery_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &cep18_token,
+        lost,
+        new_owner,
+        0,
+    );
+    builder.exec(initiate_recovery_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_QUORUM_NOT_REACHED),
+        "{:?}",
+        error
+    );
+
+    let mut preimage = METHOD_INITIATE_RECOVERY.as_bytes().to_vec();
+    preimage.append(&mut lost.to_bytes().unwrap());
+    preimage.append(&mut new_owner.to_bytes().unwrap());
+    let approve_action_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        APPROVE_ACTION,
+        runtime_args! {
+            ACTION_HASH => blake2b(preimage),
+        },
+    )
+    .build();
+    builder
+        .exec(approve_action_request)
+        .expect_success()
+        .commit();
+
+    let initiate_recovery_request = make_cep18_initiate_recovery_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &cep18_token,
+        lost,
+        new_owner,
+        0,
+    );
+    builder
+        .exec(initiate_recovery_request)
+        .expect_success()
+        .commit();
+}
+
+#[test]
+fn should_release_holds_of_lost_key_on_finalization() {
+    let operation_id = "settlement-1";
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        RECOVERY_DELAY => 1_000u64,
+        NOTARY_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+    });
+
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    let hold_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_HOLD,
+        runtime_args! {
+            ARG_OPERATION_ID => operation_id.to_string(),
+            ARG_FROM => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_TO => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+            ARG_EXPIRATION => 10_000u64,
+        },
+    )
+    .with_block_time(500)
+    .build();
+    builder.exec(hold_request).expect_success().commit();
+
+    let initiate_recovery_request = make_cep18_initiate_recovery_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        Key::Account(*ACCOUNT_2_ADDR),
+        1_000,
+    );
+    builder
+        .exec(initiate_recovery_request)
+        .expect_success()
+        .commit();
+
+    // The whole balance of the lost key is on hold, which must not keep it from being recovered.
+    let finalize_recovery_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FINALIZE_RECOVERY,
+        runtime_args! {
+            ARG_LOST => Key::Account(*ACCOUNT_1_ADDR),
+        },
+    )
+    .with_block_time(2_000)
+    .build();
+    builder
+        .exec(finalize_recovery_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::from(TRANSFER_AMOUNT_1)
+    );
+
+    let execute_hold_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_EXECUTE_HOLD,
+        runtime_args! {
+            ARG_OPERATION_ID => operation_id.to_string(),
+        },
+    )
+    .with_block_time(3_000)
+    .build();
+    builder.exec(execute_hold_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_HOLD_NOT_FOUND),
+        "{:?}",
+        error
+    );
+}
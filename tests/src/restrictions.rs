@@ -0,0 +1,363 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ACCOUNT, ARG_ACCOUNTS, ARG_AMOUNT, ARG_CLIFF,
+        ARG_DECIMALS, ARG_DURATION, ARG_NAME, ARG_OWNER, ARG_RECIPIENT, ARG_START, ARG_SYMBOL,
+        ARG_TOTAL_SUPPLY, ENABLE_MINT_BURN, ERROR_AMOUNT_LOCKED, ERROR_NON_TRANSFERABLE,
+        ERROR_RECIPIENT_FROZEN, ERROR_RECIPIENT_NOT_WHITELISTED, ERROR_SENDER_FROZEN,
+        ERROR_SENDER_NOT_WHITELISTED, KYC_OPERATOR_LIST, METHOD_ADD_TO_WHITELIST, METHOD_BURN,
+        METHOD_FREEZE, METHOD_MINT_LOCKED, METHOD_REMOVE_FROM_WHITELIST, METHOD_TRANSFER,
+        METHOD_UNFREEZE, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+        TRANSFERABILITY, TRANSFER_ALLOW_LIST, TRANSFER_AMOUNT_1, TRANSFER_MODE,
+    },
+    installer_request_builders::{cep18_check_balance_of, setup, setup_with_args, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_not_transfer_to_or_from_frozen_account() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => transfer_amount,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FREEZE,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+        },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => transfer_amount,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECIPIENT_FROZEN),
+        "{:?}",
+        error
+    );
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => transfer_amount,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_SENDER_FROZEN),
+        "{:?}",
+        error
+    );
+
+    let unfreeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_UNFREEZE,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+        },
+    )
+    .build();
+    builder.exec(unfreeze_request).expect_success().commit();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => transfer_amount,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        transfer_amount
+    );
+}
+
+#[test]
+fn should_only_transfer_between_whitelisted_accounts() {
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        TRANSFER_MODE => 1u8,
+        KYC_OPERATOR_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => transfer_amount,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_SENDER_NOT_WHITELISTED),
+        "{:?}",
+        error
+    );
+
+    let add_to_whitelist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_ADD_TO_WHITELIST,
+        runtime_args! {
+            ARG_ACCOUNTS => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR), Key::Account(*ACCOUNT_2_ADDR)],
+        },
+    )
+    .build();
+    builder
+        .exec(add_to_whitelist_request)
+        .expect_success()
+        .commit();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => transfer_amount,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        transfer_amount
+    );
+
+    let remove_from_whitelist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_REMOVE_FROM_WHITELIST,
+        runtime_args! {
+            ARG_ACCOUNTS => vec![Key::Account(*ACCOUNT_2_ADDR)],
+        },
+    )
+    .build();
+    builder
+        .exec(remove_from_whitelist_request)
+        .expect_success()
+        .commit();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => transfer_amount,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECIPIENT_NOT_WHITELISTED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_only_transfer_released_part_of_locked_mint() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+    let locked_amount = U256::from(1_000u64);
+
+    let mint_locked_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT_LOCKED,
+        runtime_args! {
+            ARG_OWNER => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => locked_amount,
+            ARG_START => 1_000u64,
+            ARG_CLIFF => 1_000u64,
+            ARG_DURATION => 10_000u64,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(mint_locked_request).expect_success().commit();
+
+    // Nothing is released before the cliff.
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .with_block_time(1_999)
+    .build();
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_AMOUNT_LOCKED),
+        "{:?}",
+        error
+    );
+
+    // Half of the amount is released halfway through the schedule.
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => locked_amount / 2,
+        },
+    )
+    .with_block_time(6_000)
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .with_block_time(6_000)
+    .build();
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_AMOUNT_LOCKED),
+        "{:?}",
+        error
+    );
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        locked_amount / 2
+    );
+}
+
+#[test]
+fn should_only_transfer_soulbound_tokens_to_allow_listed_accounts() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        TRANSFERABILITY => 1u8,
+        TRANSFER_ALLOW_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+    });
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => transfer_amount,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_NON_TRANSFERABLE),
+        "{:?}",
+        error
+    );
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => transfer_amount,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    // Admins can burn soulbound tokens of any holder.
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => transfer_amount,
+        },
+    )
+    .build();
+    builder.exec(burn_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::zero()
+    );
+}
@@ -0,0 +1,94 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ADMIN_LIST, ARG_DECIMALS, ARG_NAME, ARG_SYMBOL,
+        ARG_TOTAL_SUPPLY, CHANGE_SECURITY, ENABLE_MINT_BURN, MINTER_LIST, NONE_LIST,
+        ROLE_MEMBERS_KEY, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{setup_with_args, TestContext},
+};
+
+/// Reads the keys holding the security badge `badge`.
+fn get_role_members(
+    builder: &InMemoryWasmTestBuilder,
+    cep18_token: &ContractHash,
+    badge: u8,
+) -> Vec<Key> {
+    let role_members_uref = builder
+        .get_contract(*cep18_token)
+        .expect("should have contract")
+        .named_keys()
+        .get(ROLE_MEMBERS_KEY)
+        .and_then(Key::as_uref)
+        .copied()
+        .expect("should have role members dictionary");
+    let count: u32 = builder
+        .query_dictionary_item(None, role_members_uref, &badge.to_string())
+        .ok()
+        .and_then(|value| value.as_cl_value().cloned())
+        .map(|value| value.into_t().expect("should be a count"))
+        .unwrap_or_default();
+    (0..count)
+        .map(|index| {
+            builder
+                .query_dictionary_item(None, role_members_uref, &format!("{badge}_{index}"))
+                .expect("should have role member")
+                .as_cl_value()
+                .cloned()
+                .expect("should be a CLValue")
+                .into_t()
+                .expect("should be a key")
+        })
+        .collect()
+}
+
+#[test]
+fn should_keep_role_members_consistent_with_badges() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => 1u8,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+    });
+
+    let mut admins = get_role_members(&builder, &cep18_token, 0);
+    admins.sort();
+    let mut expected_admins = vec![
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::Account(*ACCOUNT_1_ADDR),
+    ];
+    expected_admins.sort();
+    assert_eq!(admins, expected_admins);
+    assert_eq!(
+        get_role_members(&builder, &cep18_token, 1),
+        vec![Key::Account(*ACCOUNT_2_ADDR)]
+    );
+
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {
+            NONE_LIST => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)],
+            ADMIN_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+        },
+    )
+    .build();
+    builder
+        .exec(change_security_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        get_role_members(&builder, &cep18_token, 0),
+        vec![Key::Account(*ACCOUNT_1_ADDR), Key::Account(*ACCOUNT_2_ADDR)]
+    );
+    assert!(get_role_members(&builder, &cep18_token, 1).is_empty());
+}
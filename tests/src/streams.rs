@@ -0,0 +1,279 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_ACCOUNT, ARG_AMOUNT, ARG_DEPOSIT, ARG_RECIPIENT, ARG_START, ARG_STOP,
+        ARG_STREAM_ID, ERROR_AMOUNT_NOT_STREAMED, ERROR_RECIPIENT_FROZEN, METHOD_CANCEL_STREAM,
+        METHOD_CREATE_STREAM, METHOD_FREEZE, METHOD_WITHDRAW_FROM_STREAM, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{cep18_check_balance_of, setup, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_stream_deposit_linearly_and_split_it_on_cancel() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let deposit = U256::from(1_000u64);
+
+    let create_stream_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CREATE_STREAM,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_DEPOSIT => deposit,
+            ARG_START => 10_000u64,
+            ARG_STOP => 20_000u64,
+        },
+    )
+    .build();
+    builder
+        .exec(create_stream_request)
+        .expect_success()
+        .commit();
+
+    // Only a quarter of the deposit is streamed at block time 12_500.
+    let withdraw_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_WITHDRAW_FROM_STREAM,
+        runtime_args! {
+            ARG_STREAM_ID => 0u64,
+            ARG_AMOUNT => U256::from(251u64),
+        },
+    )
+    .with_block_time(12_500)
+    .build();
+    builder.exec(withdraw_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_AMOUNT_NOT_STREAMED),
+        "{:?}",
+        error
+    );
+
+    let withdraw_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_WITHDRAW_FROM_STREAM,
+        runtime_args! {
+            ARG_STREAM_ID => 0u64,
+            ARG_AMOUNT => U256::from(250u64),
+        },
+    )
+    .with_block_time(12_500)
+    .build();
+    builder.exec(withdraw_request).expect_success().commit();
+
+    let cancel_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CANCEL_STREAM,
+        runtime_args! {
+            ARG_STREAM_ID => 0u64,
+        },
+    )
+    .with_block_time(15_000)
+    .build();
+    builder.exec(cancel_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        deposit / 2
+    );
+    assert_eq!(
+        cep18_check_balance_of(
+            &mut builder,
+            &cep18_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(TOKEN_TOTAL_SUPPLY) - deposit / 2
+    );
+}
+
+#[test]
+fn should_stream_nothing_before_start_and_whole_deposit_after_stop() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let deposit = U256::from(1_000u64);
+
+    let create_stream_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CREATE_STREAM,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_DEPOSIT => deposit,
+            ARG_START => 10_000u64,
+            ARG_STOP => 20_000u64,
+        },
+    )
+    .build();
+    builder
+        .exec(create_stream_request)
+        .expect_success()
+        .commit();
+
+    let withdraw_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_WITHDRAW_FROM_STREAM,
+        runtime_args! {
+            ARG_STREAM_ID => 0u64,
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .with_block_time(5_000)
+    .build();
+    builder.exec(withdraw_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_AMOUNT_NOT_STREAMED),
+        "{:?}",
+        error
+    );
+
+    let withdraw_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_WITHDRAW_FROM_STREAM,
+        runtime_args! {
+            ARG_STREAM_ID => 0u64,
+            ARG_AMOUNT => deposit + 1,
+        },
+    )
+    .with_block_time(25_000)
+    .build();
+    builder.exec(withdraw_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_AMOUNT_NOT_STREAMED),
+        "{:?}",
+        error
+    );
+
+    let withdraw_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_WITHDRAW_FROM_STREAM,
+        runtime_args! {
+            ARG_STREAM_ID => 0u64,
+            ARG_AMOUNT => deposit,
+        },
+    )
+    .with_block_time(25_000)
+    .build();
+    builder.exec(withdraw_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        deposit
+    );
+}
+
+#[test]
+fn should_return_whole_deposit_when_cancelled_before_start() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let deposit = U256::from(1_000u64);
+
+    let create_stream_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CREATE_STREAM,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_DEPOSIT => deposit,
+            ARG_START => 10_000u64,
+            ARG_STOP => 20_000u64,
+        },
+    )
+    .build();
+    builder
+        .exec(create_stream_request)
+        .expect_success()
+        .commit();
+
+    let cancel_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_CANCEL_STREAM,
+        runtime_args! {
+            ARG_STREAM_ID => 0u64,
+        },
+    )
+    .with_block_time(5_000)
+    .build();
+    builder.exec(cancel_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_balance_of(
+            &mut builder,
+            &cep18_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+}
+
+#[test]
+fn should_not_withdraw_from_stream_to_frozen_recipient() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let create_stream_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CREATE_STREAM,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_DEPOSIT => U256::from(1_000u64),
+            ARG_START => 10_000u64,
+            ARG_STOP => 20_000u64,
+        },
+    )
+    .build();
+    builder
+        .exec(create_stream_request)
+        .expect_success()
+        .commit();
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FREEZE,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+        },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
+
+    let withdraw_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_WITHDRAW_FROM_STREAM,
+        runtime_args! {
+            ARG_STREAM_ID => 0u64,
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .with_block_time(25_000)
+    .build();
+    builder.exec(withdraw_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECIPIENT_FROZEN),
+        "{:?}",
+        error
+    );
+}
@@ -4,13 +4,15 @@ use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
 use crate::utility::{
     constants::{
         ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ALLOWANCE_AMOUNT_1, ARG_AMOUNT, ARG_OWNER, ARG_RECIPIENT,
-        ARG_SPENDER, ARG_TOKEN_CONTRACT, ERROR_INSUFFICIENT_BALANCE, METHOD_APPROVE,
-        METHOD_FROM_AS_STORED_CONTRACT, METHOD_TRANSFER, METHOD_TRANSFER_FROM, TOKEN_TOTAL_SUPPLY,
-        TOTAL_SUPPLY_KEY, TRANSFER_AMOUNT_1,
+        ARG_SPENDER, ARG_TOKEN_CONTRACT, ERROR_INSUFFICIENT_ALLOWANCE, ERROR_INSUFFICIENT_BALANCE,
+        METHOD_APPROVE, METHOD_FROM_AS_STORED_CONTRACT, METHOD_TRANSFER, METHOD_TRANSFER_FROM,
+        METHOD_TRY_TRANSFER_AS_STORED_CONTRACT, METHOD_TRY_TRANSFER_FROM_AS_STORED_CONTRACT,
+        TOKEN_TOTAL_SUPPLY, TOTAL_SUPPLY_KEY, TRANSFER_AMOUNT_1,
     },
     installer_request_builders::{
-        cep18_check_allowance_of, cep18_check_balance_of, make_cep18_approve_request,
-        make_cep18_transfer_request, setup, test_cep18_transfer, TestContext,
+        cep18_check_allowance_of, cep18_check_balance_of, get_test_result,
+        make_cep18_approve_request, make_cep18_transfer_request, setup, test_cep18_transfer,
+        TestContext,
     },
 };
 
@@ -526,3 +528,126 @@ fn should_transfer_account_to_account() {
         recipient2,
     );
 }
+
+#[test]
+fn should_return_error_code_from_try_transfer() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+
+    let test_contract_key = Key::Hash(cep18_test_contract_package.value());
+    let try_transfer_request = || {
+        ExecuteRequestBuilder::versioned_contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_test_contract_package,
+            None,
+            METHOD_TRY_TRANSFER_AS_STORED_CONTRACT,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => Key::from(cep18_token),
+                ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+                ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+            },
+        )
+        .build()
+    };
+
+    builder
+        .exec(try_transfer_request())
+        .expect_success()
+        .commit();
+    let result: Result<(), u32> = get_test_result(&mut builder, cep18_test_contract_package);
+    assert_eq!(result, Err(u32::from(ERROR_INSUFFICIENT_BALANCE)));
+
+    let fund_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        test_contract_key,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(fund_request).expect_success().commit();
+
+    builder
+        .exec(try_transfer_request())
+        .expect_success()
+        .commit();
+    let result: Result<(), u32> = get_test_result(&mut builder, cep18_test_contract_package);
+    assert_eq!(result, Ok(()));
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        U256::from(TRANSFER_AMOUNT_1)
+    );
+}
+
+#[test]
+fn should_return_error_code_from_try_transfer_from() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let test_contract_key = Key::Hash(cep18_test_contract_package.value());
+    let allowance = U256::from(ALLOWANCE_AMOUNT_1);
+
+    let approve_request =
+        make_cep18_approve_request(owner, &cep18_token, test_contract_key, allowance);
+    builder.exec(approve_request).expect_success().commit();
+
+    let try_transfer_from_request = |amount: U256| {
+        ExecuteRequestBuilder::versioned_contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_test_contract_package,
+            None,
+            METHOD_TRY_TRANSFER_FROM_AS_STORED_CONTRACT,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => Key::from(cep18_token),
+                ARG_OWNER => owner,
+                ARG_RECIPIENT => recipient,
+                ARG_AMOUNT => amount,
+            },
+        )
+        .build()
+    };
+
+    builder
+        .exec(try_transfer_from_request(allowance + U256::one()))
+        .expect_success()
+        .commit();
+    let result: Result<(), u32> = get_test_result(&mut builder, cep18_test_contract_package);
+    assert_eq!(result, Err(u32::from(ERROR_INSUFFICIENT_ALLOWANCE)));
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, test_contract_key),
+        allowance
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::zero()
+    );
+
+    builder
+        .exec(try_transfer_from_request(allowance))
+        .expect_success()
+        .commit();
+    let result: Result<(), u32> = get_test_result(&mut builder, cep18_test_contract_package);
+    assert_eq!(result, Ok(()));
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, test_contract_key),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        allowance
+    );
+}
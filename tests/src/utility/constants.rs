@@ -25,7 +25,7 @@ pub const ERROR_OVERFLOW: u16 = 60003;
 
 pub const TOKEN_NAME: &str = "CasperTest";
 pub const TOKEN_SYMBOL: &str = "CSPRT";
-pub const TOKEN_DECIMALS: u8 = 100;
+pub const TOKEN_DECIMALS: u8 = 8;
 pub const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
 
 pub const METHOD_TRANSFER: &str = "transfer";
@@ -81,3 +81,131 @@ pub const ADMIN_LIST: &str = "admin_list";
 pub const MINTER_LIST: &str = "minter_list";
 pub const NONE_LIST: &str = "none_list";
 pub const CHANGE_SECURITY: &str = "change_security";
+pub const APPROVE_ACTION: &str = "approve_action";
+pub const ADMIN_QUORUM: &str = "admin_quorum";
+pub const MINT_QUORUM_THRESHOLD: &str = "mint_quorum_threshold";
+pub const ACTION_HASH: &str = "action_hash";
+pub const ERROR_INSUFFICIENT_RIGHTS: u16 = 60010;
+pub const ERROR_QUORUM_NOT_REACHED: u16 = 60019;
+pub const ERROR_ACTION_ALREADY_APPROVED: u16 = 60020;
+pub const MASTER_MINTER_LIST: &str = "master_minter_list";
+pub const CONFIGURE_MINTER: &str = "configure_minter";
+pub const REMOVE_MINTER: &str = "remove_minter";
+pub const ARG_MINTER: &str = "minter";
+pub const ARG_ALLOWANCE: &str = "allowance";
+pub const ERROR_MINTER_ALLOWANCE_EXCEEDED: u16 = 60022;
+pub const SET_MINT_RATE_LIMIT: &str = "set_mint_rate_limit";
+pub const ARG_EPOCH_DURATION: &str = "epoch_duration";
+pub const ARG_EPOCH_LIMIT: &str = "epoch_limit";
+pub const ERROR_MINT_RATE_LIMIT_EXCEEDED: u16 = 60024;
+pub const METHOD_FREEZE: &str = "freeze";
+pub const METHOD_UNFREEZE: &str = "unfreeze";
+pub const ARG_ACCOUNT: &str = "account";
+pub const ERROR_SENDER_FROZEN: u16 = 60025;
+pub const ERROR_RECIPIENT_FROZEN: u16 = 60026;
+pub const TRANSFER_MODE: &str = "transfer_mode";
+pub const KYC_OPERATOR_LIST: &str = "kyc_operator_list";
+pub const METHOD_ADD_TO_WHITELIST: &str = "add_to_whitelist";
+pub const METHOD_REMOVE_FROM_WHITELIST: &str = "remove_from_whitelist";
+pub const ARG_ACCOUNTS: &str = "accounts";
+pub const ERROR_SENDER_NOT_WHITELISTED: u16 = 60028;
+pub const ERROR_RECIPIENT_NOT_WHITELISTED: u16 = 60029;
+pub const METHOD_MINT_LOCKED: &str = "mint_locked";
+pub const ARG_START: &str = "start";
+pub const ARG_CLIFF: &str = "cliff";
+pub const ARG_DURATION: &str = "duration";
+pub const ERROR_AMOUNT_LOCKED: u16 = 60031;
+pub const ENFORCEMENT_MODE: &str = "enforcement_mode";
+pub const ENFORCER_LIST: &str = "enforcer_list";
+pub const METHOD_FORCE_TRANSFER: &str = "force_transfer";
+pub const METHOD_CLAWBACK: &str = "clawback";
+pub const ARG_FROM: &str = "from";
+pub const ARG_TO: &str = "to";
+pub const ARG_REASON: &str = "reason";
+pub const ERROR_ENFORCEMENT_DISABLED: u16 = 60034;
+pub const RECOVERY_DELAY: &str = "recovery_delay";
+pub const METHOD_INITIATE_RECOVERY: &str = "initiate_recovery";
+pub const METHOD_FINALIZE_RECOVERY: &str = "finalize_recovery";
+pub const ARG_LOST: &str = "lost";
+pub const ARG_NEW_OWNER: &str = "new_owner";
+pub const ERROR_NO_PENDING_RECOVERY: u16 = 60038;
+pub const ERROR_RECOVERY_CHALLENGE_PERIOD: u16 = 60039;
+pub const NOTARY_LIST: &str = "notary_list";
+pub const METHOD_HOLD: &str = "hold";
+pub const METHOD_EXECUTE_HOLD: &str = "execute_hold";
+pub const ARG_OPERATION_ID: &str = "operation_id";
+pub const ARG_EXPIRATION: &str = "expiration";
+pub const ERROR_AMOUNT_ON_HOLD: u16 = 60044;
+pub const ERROR_HOLD_NOT_FOUND: u16 = 60042;
+pub const DEFAULT_OPERATORS: &str = "default_operators";
+pub const METHOD_REVOKE_OPERATOR: &str = "revoke_operator";
+pub const METHOD_OPERATOR_SEND: &str = "operator_send";
+pub const ARG_OPERATOR: &str = "operator";
+pub const ARG_HOLDER: &str = "holder";
+pub const ARG_DATA: &str = "data";
+pub const ERROR_NOT_OPERATOR_FOR_HOLDER: u16 = 60046;
+pub const TRANSFERABILITY: &str = "transferability";
+pub const TRANSFER_ALLOW_LIST: &str = "transfer_allow_list";
+pub const ERROR_NON_TRANSFERABLE: u16 = 60048;
+pub const INTEREST_MODE: &str = "interest_mode";
+pub const INTEREST_RATE: &str = "interest_rate";
+pub const METHOD_SET_RATE: &str = "set_rate";
+pub const ARG_RATE: &str = "rate";
+pub const ERROR_INTEREST_DISABLED: u16 = 60051;
+pub const METHOD_SEND_CLAIMABLE: &str = "send_claimable";
+pub const METHOD_CLAIM: &str = "claim";
+pub const METHOD_RECLAIM: &str = "reclaim";
+pub const ARG_EXPIRY: &str = "expiry";
+pub const ARG_TRANSFER_ID: &str = "transfer_id";
+pub const ERROR_CLAIM_NOT_EXPIRED: u16 = 60055;
+pub const METHOD_CREATE_STREAM: &str = "create_stream";
+pub const METHOD_WITHDRAW_FROM_STREAM: &str = "withdraw_from_stream";
+pub const METHOD_CANCEL_STREAM: &str = "cancel_stream";
+pub const ARG_DEPOSIT: &str = "deposit";
+pub const ARG_STOP: &str = "stop";
+pub const ARG_STREAM_ID: &str = "stream_id";
+pub const ERROR_AMOUNT_NOT_STREAMED: u16 = 60058;
+pub const METHOD_HTLC_LOCK: &str = "htlc_lock";
+pub const METHOD_HTLC_REDEEM: &str = "htlc_redeem";
+pub const METHOD_HTLC_REFUND: &str = "htlc_refund";
+pub const ARG_LOCK_ID: &str = "lock_id";
+pub const ARG_HASHLOCK: &str = "hashlock";
+pub const ARG_TIMELOCK: &str = "timelock";
+pub const ARG_PREIMAGE: &str = "preimage";
+pub const ERROR_HTLC_NOT_FOUND: u16 = 60060;
+pub const ERROR_INVALID_PREIMAGE: u16 = 60061;
+pub const ERROR_HTLC_EXPIRED: u16 = 60062;
+pub const ERROR_HTLC_NOT_EXPIRED: u16 = 60063;
+pub const METHOD_SET_MODALITIES: &str = "set_modalities";
+pub const EVENTS_MODE: &str = "events_mode";
+pub const EVENTS_SCHEMA_KEY: &str = "__events_schema";
+pub const EVENTS_KEY: &str = "events";
+pub const EVENTS_LENGTH_KEY: &str = "events_length";
+pub const METHOD_INIT_DISTRIBUTE: &str = "init_distribute";
+pub const ARG_INITIAL_BALANCES: &str = "initial_balances";
+pub const ARG_CHUNKED_DISTRIBUTION: &str = "chunked_distribution";
+pub const ERROR_INVALID_INITIAL_BALANCES: u16 = 60064;
+pub const ERROR_INITIAL_DISTRIBUTION_PENDING: u16 = 60065;
+pub const ERROR_INVALID_DECIMALS: u16 = 60068;
+pub const ERROR_DUPLICATE_LIST_ENTRY: u16 = 60069;
+pub const ERROR_NAMED_KEY_ALREADY_EXISTS: u16 = 60071;
+pub const METHOD_SET_METADATA: &str = "set_metadata";
+pub const ARG_DESCRIPTION: &str = "description";
+pub const ARG_ICON_URL: &str = "icon_url";
+pub const ARG_WEBSITE: &str = "website";
+pub const ARG_METADATA: &str = "metadata";
+pub const SCHEMA_VERSION_KEY: &str = "schema_version";
+pub const CHECK_SUPPORTED_INTERFACES_ENTRYPOINT: &str = "check_supported_interfaces";
+pub const CHECK_CONTRACT_VERSION_ENTRYPOINT: &str = "check_contract_version";
+pub const ROLE_MEMBERS_KEY: &str = "role_members";
+pub const METHOD_TRY_TRANSFER_AS_STORED_CONTRACT: &str = "try_transfer_as_stored_contract";
+pub const CHECK_BALANCES_OF_ENTRYPOINT: &str = "check_balances_of";
+pub const CHECK_ALLOWANCES_OF_ENTRYPOINT: &str = "check_allowances_of";
+pub const ARG_ADDRESSES: &str = "addresses";
+pub const ARG_OWNER_SPENDER_PAIRS: &str = "owner_spender_pairs";
+pub const SET_QUORUM: &str = "set_quorum";
+pub const ERROR_QUORUM_EXCEEDS_ADMINS: u16 = 60073;
+pub const ERROR_CUSTODY_KEY_RESTRICTED: u16 = 60074;
+pub const ERROR_INVALID_CLAIM_AMOUNT: u16 = 60075;
+pub const METHOD_TRY_TRANSFER_FROM_AS_STORED_CONTRACT: &str =
+    "try_transfer_from_as_stored_contract";
@@ -13,11 +13,14 @@ use crate::utility::constants::{
 };
 
 use super::constants::{
-    ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ADDRESS, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER,
-    ARG_RECIPIENT, ARG_SPENDER, ARG_SYMBOL, ARG_TOKEN_CONTRACT, ARG_TOTAL_SUPPLY,
-    CEP18_CONTRACT_WASM, CEP18_TEST_CONTRACT_KEY, CEP18_TEST_CONTRACT_WASM,
-    CEP18_TOKEN_CONTRACT_KEY, CHECK_ALLOWANCE_OF_ENTRYPOINT, CHECK_BALANCE_OF_ENTRYPOINT,
+    ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ADDRESS, ARG_ADDRESSES, ARG_AMOUNT, ARG_DECIMALS,
+    ARG_EXPIRATION, ARG_EXPIRY, ARG_FROM, ARG_LOST, ARG_NAME, ARG_NEW_OWNER, ARG_OPERATION_ID,
+    ARG_OWNER, ARG_OWNER_SPENDER_PAIRS, ARG_RECIPIENT, ARG_SPENDER, ARG_SYMBOL, ARG_TO,
+    ARG_TOKEN_CONTRACT, ARG_TOTAL_SUPPLY, CEP18_CONTRACT_WASM, CEP18_TEST_CONTRACT_KEY,
+    CEP18_TEST_CONTRACT_WASM, CEP18_TOKEN_CONTRACT_KEY, CHECK_ALLOWANCES_OF_ENTRYPOINT,
+    CHECK_ALLOWANCE_OF_ENTRYPOINT, CHECK_BALANCES_OF_ENTRYPOINT, CHECK_BALANCE_OF_ENTRYPOINT,
     CHECK_TOTAL_SUPPLY_ENTRYPOINT, METHOD_APPROVE, METHOD_APPROVE_AS_STORED_CONTRACT,
+    METHOD_EXECUTE_HOLD, METHOD_HOLD, METHOD_INITIATE_RECOVERY, METHOD_SEND_CLAIMABLE,
     METHOD_TRANSFER, METHOD_TRANSFER_AS_STORED_CONTRACT, RESULT_KEY, TOKEN_DECIMALS, TOKEN_NAME,
     TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
 };
@@ -233,6 +236,72 @@ pub(crate) fn cep18_check_allowance_of(
     get_test_result(builder, cep18_test_contract_package)
 }
 
+pub(crate) fn cep18_check_balances_of(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    addresses: Vec<Key>,
+) -> Vec<U256> {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_balances_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ADDRESSES => addresses,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_BALANCES_OF_ENTRYPOINT,
+        check_balances_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_allowances_of(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    owner_spender_pairs: Vec<(Key, Key)>,
+) -> Vec<U256> {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_allowances_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_OWNER_SPENDER_PAIRS => owner_spender_pairs,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_ALLOWANCES_OF_ENTRYPOINT,
+        check_allowances_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
 pub(crate) fn test_cep18_transfer(
     builder: &mut InMemoryWasmTestBuilder,
     test_context: &TestContext,
@@ -362,6 +431,88 @@ pub(crate) fn make_cep18_approve_request(
     }
 }
 
+pub(crate) fn make_cep18_send_claimable_request(
+    sender: AccountHash,
+    cep18_token: &ContractHash,
+    recipient: Key,
+    amount: U256,
+    expiry: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        *cep18_token,
+        METHOD_SEND_CLAIMABLE,
+        runtime_args! {
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => amount,
+            ARG_EXPIRY => expiry,
+        },
+    )
+    .build()
+}
+
+pub(crate) fn make_cep18_hold_request(
+    notary: AccountHash,
+    cep18_token: &ContractHash,
+    operation_id: &str,
+    from: Key,
+    to: Key,
+    amount: U256,
+    expiration: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        notary,
+        *cep18_token,
+        METHOD_HOLD,
+        runtime_args! {
+            ARG_OPERATION_ID => operation_id.to_string(),
+            ARG_FROM => from,
+            ARG_TO => to,
+            ARG_AMOUNT => amount,
+            ARG_EXPIRATION => expiration,
+        },
+    )
+    .build()
+}
+
+pub(crate) fn make_cep18_execute_hold_request(
+    notary: AccountHash,
+    cep18_token: &ContractHash,
+    operation_id: &str,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        notary,
+        *cep18_token,
+        METHOD_EXECUTE_HOLD,
+        runtime_args! {
+            ARG_OPERATION_ID => operation_id.to_string(),
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+pub(crate) fn make_cep18_initiate_recovery_request(
+    admin: AccountHash,
+    cep18_token: &ContractHash,
+    lost: Key,
+    new_owner: Key,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        *cep18_token,
+        METHOD_INITIATE_RECOVERY,
+        runtime_args! {
+            ARG_LOST => lost,
+            ARG_NEW_OWNER => new_owner,
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
 pub(crate) fn test_approve_for(
     builder: &mut InMemoryWasmTestBuilder,
     test_context: &TestContext,